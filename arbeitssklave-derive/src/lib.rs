@@ -0,0 +1,294 @@
+use proc_macro::{
+    TokenStream,
+};
+
+use quote::{
+    quote,
+    format_ident,
+};
+
+use syn::{
+    parse_quote,
+    parse_macro_input,
+    punctuated::{
+        Punctuated,
+    },
+    spanned::{
+        Spanned,
+    },
+    Data,
+    Path,
+    Type,
+    Token,
+    Ident,
+    Fields,
+    Variant,
+    DeriveInput,
+    PathArguments,
+    GenericParam,
+    GenericArgument,
+};
+
+#[proc_macro_derive(ArbeitssklaveJob, attributes(arbeitssklave))]
+pub fn derive_arbeitssklave_job(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    match expand(derive_input) {
+        Ok(token_stream) =>
+            token_stream.into(),
+        Err(error) =>
+            error.to_compile_error().into(),
+    }
+}
+
+enum JobVariant {
+    Sklave {
+        ident: Ident,
+        sklave_job: Type,
+        sklavenwelt: Type,
+        befehl: Type,
+    },
+    Lauf {
+        ident: Ident,
+        sklave_job: Type,
+        run: Path,
+    },
+    Unter {
+        ident: Ident,
+        job: Type,
+        job_unit: Type,
+        forward: Vec<Type>,
+    },
+}
+
+fn expand(derive_input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data_enum) = &derive_input.data else {
+        return Err(syn::Error::new(
+            derive_input.ident.span(),
+            "ArbeitssklaveJob can only be derived for enums",
+        ));
+    };
+
+    let job_param: Ident = format_ident!("J");
+    if derive_input.generics.type_params().any(|type_param| type_param.ident == job_param) {
+        return Err(syn::Error::new(
+            derive_input.generics.span(),
+            "generic parameter `J` is reserved for the thread pool job type",
+        ));
+    }
+
+    let job_variants = data_enum.variants
+        .iter()
+        .map(parse_variant)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let vis = &derive_input.vis;
+    let name = &derive_input.ident;
+    let unit_name = format_ident!("{}Unit", name);
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    let mut from_impls = Vec::new();
+    for job_variant in &job_variants {
+        match job_variant {
+            JobVariant::Sklave { ident, sklave_job, .. } | JobVariant::Lauf { ident, sklave_job, .. } =>
+                from_impls.push(quote! {
+                    impl #impl_generics ::std::convert::From<#sklave_job> for #name #ty_generics #where_clause {
+                        fn from(job: #sklave_job) -> Self {
+                            #name::#ident(job)
+                        }
+                    }
+                }),
+            JobVariant::Unter { ident, job, forward, .. } => {
+                from_impls.push(quote! {
+                    impl #impl_generics ::std::convert::From<#job> for #name #ty_generics #where_clause {
+                        fn from(job: #job) -> Self {
+                            #name::#ident(job)
+                        }
+                    }
+                });
+                for sklave_job in forward {
+                    from_impls.push(quote! {
+                        impl #impl_generics ::std::convert::From<#sklave_job> for #name #ty_generics #where_clause {
+                            fn from(job: #sklave_job) -> Self {
+                                #name::#ident(::std::convert::From::from(job))
+                            }
+                        }
+                    });
+                }
+            },
+        }
+    }
+
+    let mut unit_generics = derive_input.generics.clone();
+    unit_generics.params.push(GenericParam::Type(parse_quote!(#job_param)));
+    let (unit_impl_generics, unit_ty_generics, unit_where_clause) = unit_generics.split_for_impl();
+
+    let mut run_generics = unit_generics.clone();
+    let run_where_clause = run_generics.make_where_clause();
+    let mut run_arms = Vec::new();
+    for job_variant in &job_variants {
+        match job_variant {
+            JobVariant::Lauf { ident, sklave_job, run, } => {
+                run_where_clause.predicates.push(parse_quote! {
+                    #job_param: ::std::convert::From<#sklave_job>
+                });
+                run_arms.push(quote! {
+                    #name::#ident(sklave_job) =>
+                        #run(sklave_job, &self.0.handle),
+                });
+            },
+            JobVariant::Sklave { ident, sklavenwelt, befehl, .. } => {
+                run_where_clause.predicates.push(parse_quote! {
+                    #sklavenwelt: ::arbeitssklave::arbeit::Arbeitssklave<#befehl, #job_param>
+                });
+                run_arms.push(quote! {
+                    #name::#ident(sklave_job) => {
+                        let meister = sklave_job.meister();
+                        if let ::std::result::Result::Err(error) = sklave_job.arbeiten(&self.0.handle) {
+                            ::arbeitssklave::arbeit::log_job_error(&meister.kennung(), error);
+                        }
+                    },
+                });
+            },
+            JobVariant::Unter { ident, job, job_unit, .. } => {
+                run_where_clause.predicates.push(parse_quote! {
+                    #job_unit: ::edeltraud::Job + ::std::convert::From<::edeltraud::JobUnit<#job_param, #job>>
+                });
+                run_arms.push(quote! {
+                    #name::#ident(job) => {
+                        let job_unit = <#job_unit as ::std::convert::From<_>>::from(
+                            ::edeltraud::JobUnit { handle: self.0.handle, job, },
+                        );
+                        ::edeltraud::Job::run(job_unit);
+                    },
+                });
+            },
+        }
+    }
+    let (run_impl_generics, _, run_where_clause) = run_generics.split_for_impl();
+
+    Ok(quote! {
+        #(#from_impls)*
+
+        #vis struct #unit_name #unit_impl_generics (pub ::edeltraud::JobUnit<#job_param, #name #ty_generics>) #unit_where_clause;
+
+        impl #unit_impl_generics ::std::convert::From<::edeltraud::JobUnit<#job_param, #name #ty_generics>> for #unit_name #unit_ty_generics #unit_where_clause {
+            fn from(job_unit: ::edeltraud::JobUnit<#job_param, #name #ty_generics>) -> Self {
+                Self(job_unit)
+            }
+        }
+
+        impl #run_impl_generics ::edeltraud::Job for #unit_name #unit_ty_generics #run_where_clause {
+            fn run(self) {
+                match self.0.job {
+                    #(#run_arms)*
+                }
+            }
+        }
+    })
+}
+
+fn parse_variant(variant: &Variant) -> syn::Result<JobVariant> {
+    let field_type = match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 =>
+            fields.unnamed[0].ty.clone(),
+        _ =>
+            return Err(syn::Error::new(
+                variant.span(),
+                "ArbeitssklaveJob variants must have exactly one unnamed field",
+            )),
+    };
+
+    let mut maybe_job_unit = None;
+    let mut maybe_run = None;
+    let mut forward = Vec::new();
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("arbeitssklave") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("job_unit") {
+                maybe_job_unit = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else if meta.path.is_ident("forward") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                forward.extend(Punctuated::<Type, Token![,]>::parse_terminated(&content)?);
+                Ok(())
+            } else if meta.path.is_ident("run") {
+                maybe_run = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `job_unit = ...`, `forward(...)` or `run = ...`"))
+            }
+        })?;
+    }
+
+    if let Some(job_unit) = maybe_job_unit {
+        if maybe_run.is_some() {
+            return Err(syn::Error::new(
+                variant.span(),
+                "`run = ...` can not be combined with `job_unit = ...`",
+            ));
+        }
+        return Ok(JobVariant::Unter {
+            ident: variant.ident.clone(),
+            job: field_type,
+            job_unit,
+            forward,
+        });
+    }
+    if !forward.is_empty() {
+        return Err(syn::Error::new(
+            variant.span(),
+            "`forward(...)` requires `job_unit = ...` to be specified",
+        ));
+    }
+
+    match sklave_job_args(&field_type) {
+        Some(..) if let Some(run) = maybe_run =>
+            Ok(JobVariant::Lauf {
+                ident: variant.ident.clone(),
+                sklave_job: field_type,
+                run,
+            }),
+        Some((sklavenwelt, befehl)) =>
+            Ok(JobVariant::Sklave {
+                ident: variant.ident.clone(),
+                sklave_job: field_type,
+                sklavenwelt,
+                befehl,
+            }),
+        None =>
+            Err(syn::Error::new(
+                field_type.span(),
+                "expected `SklaveJob<W, B>` or a sub job with `#[arbeitssklave(job_unit = ...)]`",
+            )),
+    }
+}
+
+fn sklave_job_args(field_type: &Type) -> Option<(Type, Type)> {
+    let Type::Path(type_path) = field_type else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "SklaveJob" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    let mut types = arguments.args
+        .iter()
+        .filter_map(|argument| match argument {
+            GenericArgument::Type(argument_type) =>
+                Some(argument_type.clone()),
+            _ =>
+                None,
+        });
+    let sklavenwelt = types.next()?;
+    let befehl = types.next()?;
+    if types.next().is_some() {
+        return None;
+    }
+    Some((sklavenwelt, befehl))
+}
@@ -0,0 +1,5 @@
+#[test]
+fn compile_fail() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,199 @@
+use std::{
+    sync::{
+        mpsc,
+        Mutex,
+    },
+};
+
+use arbeitssklave::{
+    komm,
+    arbeit::{
+        Arbeitssklave,
+        Context,
+    },
+    Freie,
+    Meister,
+    SklaveJob,
+};
+
+use arbeitssklave_derive::{
+    ArbeitssklaveJob,
+};
+
+mod doubler {
+    use arbeitssklave::{
+        komm,
+        arbeit::{
+            Arbeitssklave,
+            Context,
+        },
+        SklaveJob,
+    };
+
+    use arbeitssklave_derive::{
+        ArbeitssklaveJob,
+    };
+
+    pub struct Welt;
+
+    pub struct Order<E> {
+        pub value: usize,
+        pub echo: E,
+    }
+
+    impl<E, J> Arbeitssklave<Order<E>, J> for Welt where E: komm::Echo<usize> {
+        fn befehl(&mut self, order: Order<E>, _context: &mut Context<'_, Self, Order<E>, J>) {
+            order.echo.commit_echo(order.value * 2).ok();
+        }
+    }
+
+    #[derive(ArbeitssklaveJob)]
+    pub enum Job<E> {
+        Sklave(SklaveJob<Welt, Order<E>>),
+    }
+}
+
+struct Stamp;
+
+enum DriverOrder {
+    Double {
+        value: usize,
+        reply_tx: Mutex<mpsc::Sender<usize>>,
+    },
+    Doubled(komm::Umschlag<usize, Stamp>),
+    Abbrechen(komm::UmschlagAbbrechen<Stamp>),
+}
+
+impl From<komm::Umschlag<usize, Stamp>> for DriverOrder {
+    fn from(umschlag: komm::Umschlag<usize, Stamp>) -> DriverOrder {
+        DriverOrder::Doubled(umschlag)
+    }
+}
+
+impl From<komm::UmschlagAbbrechen<Stamp>> for DriverOrder {
+    fn from(umschlag_abbrechen: komm::UmschlagAbbrechen<Stamp>) -> DriverOrder {
+        DriverOrder::Abbrechen(umschlag_abbrechen)
+    }
+}
+
+type DoublerEcho = komm::Rueckkopplung<DriverOrder, Stamp>;
+type DoublerOrder = doubler::Order<DoublerEcho>;
+
+struct DriverWelt {
+    sendegeraet: komm::Sendegeraet<DriverOrder>,
+    doubler_meister: Meister<doubler::Welt, DoublerOrder>,
+    maybe_reply_tx: Option<Mutex<mpsc::Sender<usize>>>,
+}
+
+impl<J> Arbeitssklave<DriverOrder, J> for DriverWelt where J: From<SklaveJob<doubler::Welt, DoublerOrder>> {
+    fn befehl(&mut self, order: DriverOrder, context: &mut Context<'_, Self, DriverOrder, J>) {
+        match order {
+            DriverOrder::Double { value, reply_tx, } => {
+                self.maybe_reply_tx = Some(reply_tx);
+                let echo = self.sendegeraet.rueckkopplung(Stamp);
+                self.doubler_meister
+                    .befehl(doubler::Order { value, echo, }, context.thread_pool())
+                    .unwrap();
+            },
+            DriverOrder::Doubled(komm::Umschlag { inhalt, stamp: Stamp, }) => {
+                let reply_tx = self.maybe_reply_tx.take().unwrap();
+                reply_tx.lock().unwrap().send(inhalt).unwrap();
+            },
+            DriverOrder::Abbrechen(komm::UmschlagAbbrechen { stamp: Stamp, }) =>
+                panic!("unexpected UmschlagAbbrechen"),
+        }
+    }
+}
+
+#[derive(ArbeitssklaveJob)]
+enum Job {
+    Driver(SklaveJob<DriverWelt, DriverOrder>),
+    #[arbeitssklave(job_unit = doubler::JobUnit<DoublerEcho, J>, forward(SklaveJob<doubler::Welt, DoublerOrder>))]
+    Doubler(doubler::Job<DoublerEcho>),
+}
+
+#[test]
+fn nested_dispatch() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<Job, JobUnit<Job>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let doubler_meister = Freie::new()
+        .versklaven(doubler::Welt, &thread_pool)
+        .unwrap();
+
+    let driver_freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&driver_freie.meister(), thread_pool.clone());
+    let driver_meister = driver_freie
+        .versklaven(
+            DriverWelt {
+                sendegeraet,
+                doubler_meister,
+                maybe_reply_tx: None,
+            },
+            &thread_pool,
+        )
+        .unwrap();
+
+    for value in [0, 1, 17, 1024] {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        driver_meister
+            .befehl(DriverOrder::Double { value, reply_tx: Mutex::new(reply_tx), }, &thread_pool)
+            .unwrap();
+        assert_eq!(reply_rx.recv(), Ok(value * 2));
+    }
+}
+
+mod summer {
+    use std::{
+        sync::{
+            mpsc,
+        },
+    };
+
+    use arbeitssklave::{
+        SklaveJob,
+    };
+
+    pub struct Welt {
+        pub sum: usize,
+        pub sums_tx: mpsc::Sender<usize>,
+    }
+
+    pub struct Order(pub usize);
+
+    pub fn run<J>(sklave_job: SklaveJob<Welt, Order>, _thread_pool: &edeltraud::Handle<J>) {
+        sklave_job
+            .ausfuehren(|welt, Order(value)| {
+                welt.sum += value;
+                welt.sums_tx.send(welt.sum).ok();
+            })
+            .ok();
+    }
+}
+
+#[derive(ArbeitssklaveJob)]
+enum SummerJob {
+    #[arbeitssklave(run = summer::run)]
+    Summer(SklaveJob<summer::Welt, summer::Order>),
+}
+
+#[test]
+fn custom_run() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<SummerJob, SummerJobUnit<SummerJob>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (sums_tx, sums_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(summer::Welt { sum: 0, sums_tx, }, &thread_pool)
+        .unwrap();
+    for value in [1, 2, 3] {
+        meister.befehl(summer::Order(value), &thread_pool).unwrap();
+    }
+    assert_eq!(sums_rx.recv(), Ok(1));
+    assert_eq!(sums_rx.recv(), Ok(3));
+    assert_eq!(sums_rx.recv(), Ok(6));
+}
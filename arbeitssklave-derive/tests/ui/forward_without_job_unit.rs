@@ -0,0 +1,18 @@
+use arbeitssklave::SklaveJob;
+use arbeitssklave_derive::ArbeitssklaveJob;
+
+struct Welt;
+
+struct Order;
+
+enum SubJob {
+    Sklave(SklaveJob<Welt, Order>),
+}
+
+#[derive(ArbeitssklaveJob)]
+enum Job {
+    #[arbeitssklave(forward(SklaveJob<Welt, Order>))]
+    Sub(SubJob),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use arbeitssklave::SklaveJob;
+use arbeitssklave_derive::ArbeitssklaveJob;
+
+struct Welt;
+
+struct Order;
+
+#[derive(ArbeitssklaveJob)]
+enum Job {
+    Sklave(SklaveJob<Welt, Order>, usize),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use arbeitssklave::SklaveJob;
+use arbeitssklave_derive::ArbeitssklaveJob;
+
+struct Welt;
+
+struct Order;
+
+#[derive(ArbeitssklaveJob)]
+struct Job {
+    sklave: SklaveJob<Welt, Order>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use arbeitssklave::SklaveJob;
+use arbeitssklave_derive::ArbeitssklaveJob;
+
+struct Welt;
+
+struct Order<J>(J);
+
+#[derive(ArbeitssklaveJob)]
+enum Job<J> {
+    Sklave(SklaveJob<Welt, Order<J>>),
+}
+
+fn main() {}
@@ -0,0 +1,105 @@
+use crate::{
+    Dienst,
+    Error,
+    Kennung,
+    Meister,
+    Gehorsam,
+    SklaveJob,
+    SklavenBefehl,
+};
+
+pub trait Arbeitssklave<B, J>: Sized {
+    fn befehl(&mut self, order: B, context: &mut Context<'_, Self, B, J>);
+
+    fn starten(&mut self, _context: &mut Context<'_, Self, B, J>) { }
+
+    fn rasten(&mut self, _context: &mut Context<'_, Self, B, J>) { }
+
+    fn beenden(&mut self, _context: &mut Context<'_, Self, B, J>) { }
+}
+
+pub struct Context<'a, W, B, J> {
+    meister: Meister<W, B>,
+    thread_pool: &'a edeltraud::Handle<J>,
+    beenden_mark: bool,
+}
+
+impl<W, B, J> Context<'_, W, B, J> {
+    pub fn meister(&self) -> &Meister<W, B> {
+        &self.meister
+    }
+
+    pub fn thread_pool(&self) -> &edeltraud::Handle<J> {
+        self.thread_pool
+    }
+
+    pub fn beenden(&mut self) {
+        self.beenden_mark = true;
+    }
+
+    fn apply_beenden_mark(&mut self) {
+        if self.beenden_mark {
+            self.beenden_mark = false;
+            self.meister.inner.shutdown();
+        }
+    }
+}
+
+impl<W, B> SklaveJob<W, B> {
+    pub fn arbeiten<J>(mut self, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error> where W: Arbeitssklave<B, J> {
+        let mut context = Context {
+            meister: self.meister(),
+            thread_pool,
+            beenden_mark: false,
+        };
+        let inner = self.inner.clone();
+        #[cfg(feature = "tracing")]
+        let _job_span = inner.job_span().entered();
+
+        let mut is_beendet = false;
+        loop {
+            match self.dienen(!is_beendet)? {
+                Dienst::Beenden { sklave_job, } => {
+                    // reported by the same tag check that would otherwise take the sklavenwelt away
+                    self = sklave_job;
+                    is_beendet = true;
+                    let sklavenwelt = &mut *self;
+                    inner.panic_isolation(|| sklavenwelt.beenden(&mut context))?;
+                },
+                Dienst::Gehorsam(Gehorsam::Rasten) =>
+                    return Ok(()),
+                Dienst::Gehorsam(Gehorsam::Machen { mut befehle, }) => {
+                    if befehle.mark_started() {
+                        let sklavenwelt = &mut **befehle;
+                        inner.panic_isolation(|| sklavenwelt.starten(&mut context))?;
+                        context.apply_beenden_mark();
+                    }
+                    loop {
+                        match befehle.befehl() {
+                            SklavenBefehl::Mehr { befehl, mut mehr_befehle, } => {
+                                let sklavenwelt = &mut **mehr_befehle;
+                                inner.panic_isolation(|| sklavenwelt.befehl(befehl, &mut context))?;
+                                context.apply_beenden_mark();
+                                befehle = mehr_befehle;
+                            },
+                            SklavenBefehl::Ende { sklave_job, } => {
+                                self = sklave_job;
+                                if !self.is_shutting_down() {
+                                    let sklavenwelt = &mut *self;
+                                    inner.panic_isolation(|| sklavenwelt.rasten(&mut context))?;
+                                    context.apply_beenden_mark();
+                                }
+                                break;
+                            },
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn log_job_error(kennung: &Kennung, error: Error) {
+    log::error!("sklave job for {kennung} terminated with {error:?}");
+}
@@ -0,0 +1,275 @@
+use std::{
+    cell::{
+        Cell,
+    },
+    time::{
+        Instant,
+        Duration,
+    },
+    sync::{
+        atomic,
+        Arc,
+        Weak,
+        Mutex,
+    },
+    collections::{
+        VecDeque,
+    },
+};
+
+use crate::{
+    reach_sklavenwelt_mut,
+    Error,
+    Freie,
+    Inner,
+    ActorId,
+    Meister,
+    TouchTag,
+    TouchTagDecoded,
+    SklaveJob,
+    Sklavenwelt,
+    TerminationReason,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RestartStrategy {
+    OneForOne,
+    OneForAll,
+}
+
+#[derive(Clone, Debug)]
+pub struct Params {
+    pub restart_strategy: RestartStrategy,
+    pub max_restarts: usize,
+    pub restarts_window: Duration,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            restart_strategy: RestartStrategy::OneForOne,
+            max_restarts: 3,
+            restarts_window: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Aufseher {
+    inner: Arc<AufseherInner>,
+}
+
+struct AufseherInner {
+    params: Params,
+    restarts: Mutex<VecDeque<Instant>>,
+    sklaven: Mutex<Vec<(ActorId, Weak<dyn Beaufsichtigt>)>>,
+}
+
+pub(crate) struct Aufsicht<W> {
+    aufseher: Arc<AufseherInner>,
+    sklavenwelt_factory: SklavenweltFactory<W>,
+}
+
+type SklavenweltFactory<W> = Box<dyn Fn() -> W + Send + Sync + 'static>;
+
+trait Beaufsichtigt: Send + Sync {
+    fn neustart_anfordern(self: Arc<Self>);
+
+    fn aufgeben(&self);
+}
+
+impl<W, B> Beaufsichtigt for Inner<W, B> where W: Send, B: Send {
+    fn neustart_anfordern(self: Arc<Self>) {
+        self.neustart_mark.store(true, atomic::Ordering::Release);
+        // resting sklave would apply the restart only with its next order: wake it up right away
+        if self.aufwecken()
+            && let Err(error) = self.peitschen()
+        {
+            log::error!("{} failed to wake up for restart: {error:?}", self.kennung());
+        }
+    }
+
+    fn aufgeben(&self) {
+        self.shutdown();
+    }
+}
+
+impl<W, B> Inner<W, B> {
+    fn aufwecken(&self) -> bool {
+        let mut prev_tag = self.touch_tag.load();
+        loop {
+            let decoded = TouchTag::decompose(prev_tag);
+            if !decoded.is_ready || decoded.terminated.is_some() {
+                return false;
+            }
+            let new_tag = TouchTag::compose(TouchTagDecoded {
+                is_ready: false,
+                ..decoded
+            });
+            if let Err(changed_tag) = self.touch_tag.try_set(prev_tag, new_tag) {
+                prev_tag = changed_tag;
+                continue;
+            }
+            return true;
+        }
+    }
+}
+
+thread_local! {
+    static NEUSTART_SPAWN: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Aufseher {
+    pub fn new(params: Params) -> Aufseher {
+        Aufseher {
+            inner: Arc::new(AufseherInner {
+                params,
+                restarts: Mutex::new(VecDeque::new()),
+                sklaven: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    pub fn versklaven<W, B, J, F>(
+        &self,
+        freie: Freie<W, B>,
+        sklavenwelt_factory: F,
+        thread_pool: &edeltraud::Handle<J>,
+    )
+        -> Result<Meister<W, B>, Error>
+    where J: From<SklaveJob<W, B>> + Send + 'static,
+          F: Fn() -> W + Send + Sync + 'static,
+          W: Send + 'static,
+          B: Send + 'static,
+    {
+        let sklavenwelt = sklavenwelt_factory();
+        let aufsicht = Aufsicht {
+            aufseher: self.inner.clone(),
+            sklavenwelt_factory: Box::new(sklavenwelt_factory),
+        };
+        if freie.inner.aufsicht.set(aufsicht).is_err() {
+            unreachable!("aufsicht is installed only once as freie is consumed");
+        }
+
+        if let Ok(mut sklaven) = self.inner.sklaven.lock() {
+            sklaven.retain(|(_, sklave)| sklave.strong_count() > 0);
+            let sklave: Weak<dyn Beaufsichtigt> = Arc::downgrade(&freie.inner) as _;
+            sklaven.push((freie.inner.actor_id, sklave));
+        }
+
+        freie.versklaven(sklavenwelt, thread_pool)
+    }
+}
+
+impl AufseherInner {
+    fn neustart_erlauben(&self) -> bool {
+        let Ok(mut restarts) = self.restarts.lock() else {
+            return false;
+        };
+        let now = Instant::now();
+        while let Some(restarted_at) = restarts.front()
+            && now.duration_since(*restarted_at) > self.params.restarts_window
+        {
+            restarts.pop_front();
+        }
+        if restarts.len() >= self.params.max_restarts {
+            return false;
+        }
+        restarts.push_back(now);
+        true
+    }
+
+    fn geschwister<F>(&self, actor_id: ActorId, mut action: F) where F: FnMut(Arc<dyn Beaufsichtigt>) {
+        if self.params.restart_strategy != RestartStrategy::OneForAll {
+            return;
+        }
+        let sklaven: Vec<_> = match self.sklaven.lock() {
+            Ok(sklaven) =>
+                sklaven.iter()
+                    .filter(|(sklave_actor_id, _)| *sklave_actor_id != actor_id)
+                    .filter_map(|(_, sklave)| sklave.upgrade())
+                    .collect(),
+            Err(..) =>
+                return,
+        };
+        for sklave in sklaven {
+            action(sklave);
+        }
+    }
+}
+
+impl<W, B> SklaveJob<W, B> {
+    pub(crate) fn neustarten(&mut self) -> bool {
+        let inner = self.inner.clone();
+        let Some(aufsicht) = inner.aufsicht.get() else {
+            return false;
+        };
+        if NEUSTART_SPAWN.get() {
+            // restarted job has been dropped by thread pool
+            return false;
+        }
+        if TouchTag::decompose(inner.touch_tag.load()).terminated.is_some() {
+            return false;
+        }
+        if !aufsicht.aufseher.neustart_erlauben() {
+            log::error!("{} terminated abnormally, restarts limit exceeded, giving up", inner.kennung());
+            aufsicht.aufseher.geschwister(inner.actor_id, |sklave| sklave.aufgeben());
+            return false;
+        }
+        log::warn!("{} terminated abnormally, restarting", inner.kennung());
+        if let Err(error) = self.neue_sklavenwelt(aufsicht) {
+            log::error!("{} restart failed: sklavenwelt factory {error:?}, giving up", inner.kennung());
+            return false;
+        }
+        aufsicht.aufseher.geschwister(inner.actor_id, |sklave| sklave.neustart_anfordern());
+        let Some(peitsche) = inner.peitsche.get() else {
+            return false;
+        };
+        NEUSTART_SPAWN.set(true);
+        let spawn_result = peitsche(SklaveJob::new(inner.clone()));
+        NEUSTART_SPAWN.set(false);
+        match spawn_result {
+            Ok(()) =>
+                true,
+            Err(error) => {
+                log::error!("{} restart failed: {error:?}", inner.kennung());
+                inner.terminate(TerminationReason::ThreadPoolSpawn, true);
+                false
+            },
+        }
+    }
+
+    pub(crate) fn neustart_anwenden(&mut self) {
+        if !self.inner.neustart_mark.load(atomic::Ordering::Relaxed) {
+            return;
+        }
+        if !self.inner.neustart_mark.swap(false, atomic::Ordering::Acquire) {
+            return;
+        }
+        let inner = self.inner.clone();
+        if let Some(aufsicht) = inner.aufsicht.get() {
+            log::warn!("{} restarting on aufseher request", inner.kennung());
+            if let Err(error) = self.neue_sklavenwelt(aufsicht) {
+                log::error!("{} restart failed: sklavenwelt factory {error:?}, giving up", inner.kennung());
+                inner.terminate(TerminationReason::Panicked, false);
+            }
+        }
+    }
+
+    fn neue_sklavenwelt(&mut self, aufsicht: &Aufsicht<W>) -> Result<(), Error> {
+        let sklavenwelt = self.inner.panic_isolation(|| (aufsicht.sklavenwelt_factory)())?;
+        if let Ok(mut panic_message) = self.inner.panic_message.lock() {
+            panic_message.take();
+        }
+        let maybe_sklavenwelt = reach_sklavenwelt_mut(&mut self.inner);
+        match maybe_sklavenwelt {
+            Some(sklavenwelt_mut) => {
+                sklavenwelt_mut.sklavenwelt = sklavenwelt;
+                sklavenwelt_mut.is_started = false;
+            },
+            None =>
+                *maybe_sklavenwelt = Some(Sklavenwelt::new(sklavenwelt)),
+        }
+        Ok(())
+    }
+}
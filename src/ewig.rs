@@ -6,6 +6,13 @@ use std::{
         Mutex,
     },
     thread,
+    time::{
+        Instant,
+    },
+};
+
+use crate::{
+    TerminationReason,
 };
 
 pub struct Freie<B, E> {
@@ -33,14 +40,22 @@ pub struct Sklave<B, E> {
 
 struct Inner<B, E> {
     orders: crossbeam::queue::SegQueue<B>,
-    is_terminated: atomic::AtomicBool,
+    terminated: atomic::AtomicU8,
+    is_resting: atomic::AtomicBool,
     maybe_error: Mutex<Option<E>>,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Status {
+    pub orders_count: usize,
+    pub is_resting: bool,
+    pub terminated: Option<TerminationReason>,
+}
+
 #[derive(Debug)]
 pub enum Error {
     ThreadSpawn(io::Error),
-    Terminated,
+    Terminated(TerminationReason),
 }
 
 impl<B, E> Default for Freie<B, E> {
@@ -54,7 +69,8 @@ impl<B, E> Freie<B, E> {
         Self {
             inner: Arc::new(Inner {
                 orders: crossbeam::queue::SegQueue::new(),
-                is_terminated: atomic::AtomicBool::new(false),
+                terminated: atomic::AtomicU8::new(TerminationReason::encode(None)),
+                is_resting: atomic::AtomicBool::new(false),
                 maybe_error: Mutex::new(None),
             }),
         }
@@ -86,7 +102,12 @@ impl<B, E> Freie<B, E> {
 
                 impl<B, E> Drop for DropBomp<B, E> {
                     fn drop(&mut self) {
-                        self.inner_clone.is_terminated.store(true, atomic::Ordering::SeqCst);
+                        let reason = if thread::panicking() {
+                            TerminationReason::Panicked
+                        } else {
+                            TerminationReason::JobDropped
+                        };
+                        self.inner_clone.terminate(reason);
                     }
                 }
 
@@ -95,10 +116,10 @@ impl<B, E> Freie<B, E> {
                 };
 
 
-                if let Err(error) = sklave_job(&mut sklave) {
-                    if let Ok(mut locked_maybe_error) = sklave.inner.maybe_error.lock() {
-                        *locked_maybe_error = Some(error);
-                    }
+                if let Err(error) = sklave_job(&mut sklave)
+                    && let Ok(mut locked_maybe_error) = sklave.inner.maybe_error.lock()
+                {
+                    *locked_maybe_error = Some(error);
                 }
 
 
@@ -111,14 +132,31 @@ impl<B, E> Freie<B, E> {
     }
 }
 
+impl<B, E> Inner<B, E> {
+    fn terminated(&self) -> Option<TerminationReason> {
+        TerminationReason::decode(self.terminated.load(atomic::Ordering::SeqCst))
+    }
+
+    fn terminate(&self, reason: TerminationReason) {
+        self.terminated
+            .compare_exchange(
+                TerminationReason::encode(None),
+                TerminationReason::encode(Some(reason)),
+                atomic::Ordering::SeqCst,
+                atomic::Ordering::SeqCst,
+            )
+            .ok();
+    }
+}
+
 impl<B, E> Drop for Meister<B, E> {
     fn drop(&mut self) {
-        if let Some(join_handle_arc) = self.join_handle.take() {
-            if let Ok(join_handle) = Arc::try_unwrap(join_handle_arc) {
-                self.inner.is_terminated.store(true, atomic::Ordering::SeqCst);
-                join_handle.thread().unpark();
-                join_handle.join().ok();
-            }
+        if let Some(join_handle_arc) = self.join_handle.take()
+            && let Ok(join_handle) = Arc::try_unwrap(join_handle_arc)
+        {
+            self.inner.terminate(TerminationReason::Shutdown);
+            join_handle.thread().unpark();
+            join_handle.join().ok();
         }
     }
 }
@@ -129,15 +167,15 @@ impl<B, E> Meister<B, E> where E: From<Error> {
     }
 
     pub fn befehle<I>(&self, orders: I) -> Result<(), E> where I: IntoIterator<Item = B> {
-        if self.inner.is_terminated.load(atomic::Ordering::SeqCst) {
+        if let Some(reason) = self.inner.terminated() {
             return if let Ok(mut locked_maybe_error) = self.inner.maybe_error.lock() {
                 if let Some(error) = locked_maybe_error.take() {
                     Err(error)
                 } else {
-                    Err(Error::Terminated.into())
+                    Err(Error::Terminated(reason).into())
                 }
             } else {
-                Err(Error::Terminated.into())
+                Err(Error::Terminated(reason).into())
             }
         }
 
@@ -150,24 +188,49 @@ impl<B, E> Meister<B, E> where E: From<Error> {
 
         Ok(())
     }
+
+    pub fn status(&self) -> Status {
+        Status {
+            orders_count: self.inner.orders.len(),
+            is_resting: self.inner.is_resting.load(atomic::Ordering::SeqCst),
+            terminated: self.inner.terminated(),
+        }
+    }
 }
 
 impl<B, E> Sklave<B, E> where E: From<Error> {
     pub fn zu_ihren_diensten(&mut self) -> Result<impl Iterator<Item = B> + '_, E> {
+        self.zu_ihren_diensten_bis(None)
+    }
+
+    pub fn zu_ihren_diensten_bis(&mut self, maybe_frist: Option<Instant>) -> Result<impl Iterator<Item = B> + '_, E> {
         if !self.taken_orders.is_empty() {
             return Ok(self.taken_orders.drain(..));
         }
 
         let backoff = crossbeam::utils::Backoff::new();
         loop {
-            if self.inner.is_terminated.load(atomic::Ordering::SeqCst) {
-                return Err(Error::Terminated.into());
+            if let Some(reason) = self.inner.terminated() {
+                self.inner.is_resting.store(false, atomic::Ordering::SeqCst);
+                return Err(Error::Terminated(reason).into());
             }
 
             match self.inner.orders.pop() {
                 None if self.taken_orders.is_empty() => {
                     // nothing to do, sleeping
-                    if backoff.is_completed() {
+                    self.inner.is_resting.store(true, atomic::Ordering::SeqCst);
+                    if let Some(frist) = maybe_frist {
+                        let now = Instant::now();
+                        if now >= frist {
+                            self.inner.is_resting.store(false, atomic::Ordering::SeqCst);
+                            return Ok(self.taken_orders.drain(..));
+                        }
+                        if backoff.is_completed() {
+                            thread::park_timeout(frist - now);
+                        } else {
+                            backoff.snooze();
+                        }
+                    } else if backoff.is_completed() {
                         thread::park();
                     } else {
                         backoff.snooze();
@@ -176,8 +239,10 @@ impl<B, E> Sklave<B, E> where E: From<Error> {
                 },
                 None =>
                     return Ok(self.taken_orders.drain(..)),
-                Some(order) =>
-                    self.taken_orders.push(order),
+                Some(order) => {
+                    self.inner.is_resting.store(false, atomic::Ordering::SeqCst);
+                    self.taken_orders.push(order);
+                },
             }
         }
     }
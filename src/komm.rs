@@ -1,8 +1,30 @@
 use std::{
     fmt,
+    mem,
+    convert::{
+        Infallible,
+    },
+    pin::{
+        Pin,
+    },
+    future::{
+        Future,
+    },
+    task::{
+        self,
+        Poll,
+        Wake,
+    },
+    thread,
+    time::{
+        Instant,
+        Duration,
+    },
     sync::{
+        mpsc,
         Arc,
-        Weak,
+        Mutex,
+        OnceLock,
         atomic::{
             Ordering,
             AtomicBool,
@@ -12,10 +34,16 @@ use std::{
 };
 
 use crate::{
+    zeitgeber,
+    dead_letter,
+    ActorId,
+    Kennung,
     Error,
+    SendError,
+    DeadLetterSink,
     Meister,
     SklaveJob,
-    Inner as ArbeitssklaveInner,
+    SchwachMeister,
 };
 
 // Umschlag
@@ -33,38 +61,104 @@ pub struct UmschlagAbbrechen<S> {
     pub stamp: S,
 }
 
+// UmschlagFristAbgelaufen
+
+#[derive(Debug)]
+pub struct UmschlagFristAbgelaufen<S> {
+    pub stamp: S,
+}
+
 // Echo
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct EchoError;
+pub struct EchoError<I> {
+    pub inhalt: I,
+}
 
 pub trait Echo<I> {
-    fn commit_echo(self, inhalt: I) -> Result<(), EchoError>;
+    fn commit_echo(self, inhalt: I) -> Result<(), EchoError<I>>;
 }
 
 impl<B, I, S> Echo<I> for Rueckkopplung<B, S>
 where B: From<UmschlagAbbrechen<S>>,
       B: From<Umschlag<I, S>>,
 {
-    fn commit_echo(self, inhalt: I) -> Result<(), EchoError> {
+    fn commit_echo(self, inhalt: I) -> Result<(), EchoError<I>> {
         self.commit(inhalt)
-            .map_err(|_error| EchoError)
+            .map_err(|commit_error| EchoError { inhalt: commit_error.inhalt, })
     }
 }
 
 // Rueckkopplung
 
 pub struct Rueckkopplung<B, S> where B: From<UmschlagAbbrechen<S>> + 'static {
-    maybe_stamp: Option<S>,
+    maybe_stamp: Stempel<S>,
     sendegeraet: Sendegeraet<B>,
 }
 
+#[derive(Debug)]
+enum Stempel<S> {
+    Eigen(Option<S>),
+    MitFrist {
+        maybe_stamp: Arc<Mutex<Option<S>>>,
+        maybe_abbrecher: Option<zeitgeber::Abbrecher>,
+    },
+}
+
+impl<S> Stempel<S> {
+    fn take(&mut self) -> Option<S> {
+        match self {
+            Stempel::Eigen(maybe_stamp) =>
+                maybe_stamp.take(),
+            Stempel::MitFrist { maybe_stamp, maybe_abbrecher, } => {
+                let stamp = maybe_stamp.lock().ok()?.take()?;
+                // committed or dropped before frist: timer entry is not needed anymore
+                if let Some(abbrecher) = maybe_abbrecher.take() {
+                    abbrecher.abbrechen();
+                }
+                Some(stamp)
+            },
+        }
+    }
+}
+
+pub struct CommitError<I, S> {
+    pub inhalt: I,
+    pub maybe_stamp: Option<S>,
+    pub error: CommitErrorKind,
+    pub kennung: Kennung,
+}
+
+#[derive(Debug)]
+pub enum CommitErrorKind {
+    Send(Error),
+    FristAbgelaufen,
+}
+
 impl<B, S> Rueckkopplung<B, S> where B: From<UmschlagAbbrechen<S>> {
-    pub fn commit<I>(mut self, inhalt: I) -> Result<(), Error> where B: From<Umschlag<I, S>> {
-        let stamp = self.maybe_stamp.take().unwrap();
-        let umschlag = Umschlag { inhalt, stamp, };
-        let order = umschlag.into();
-        self.sendegeraet.meister.befehl(order)
+    pub fn commit<I>(mut self, inhalt: I) -> Result<(), CommitError<I, S>> where B: From<Umschlag<I, S>> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("rueckkopplung_commit", actor = %self.sendegeraet.kennung()).entered();
+        let Some(stamp) = self.maybe_stamp.take() else {
+            // frist has expired, requester has already got UmschlagFristAbgelaufen
+            return Err(CommitError {
+                inhalt,
+                maybe_stamp: None,
+                error: CommitErrorKind::FristAbgelaufen,
+                kennung: self.sendegeraet.kennung(),
+            });
+        };
+        let mut maybe_umschlag = Some(Umschlag { inhalt, stamp, });
+        self.sendegeraet.meister
+            .befehl_mit(&mut || maybe_umschlag.take().unwrap().into())
+            .map_err(|error| {
+                let Umschlag { inhalt, stamp, } = maybe_umschlag.take().unwrap();
+                CommitError {
+                    inhalt,
+                    maybe_stamp: Some(stamp),
+                    error: CommitErrorKind::Send(error),
+                    kennung: self.sendegeraet.kennung(),
+                }
+            })
     }
 }
 
@@ -72,8 +166,106 @@ impl<B, S> Drop for Rueckkopplung<B, S> where B: From<UmschlagAbbrechen<S>> {
     fn drop(&mut self) {
         if let Some(stamp) = self.maybe_stamp.take() {
             let umschlag_abbrechen = UmschlagAbbrechen { stamp, };
-            let order = umschlag_abbrechen.into();
-            self.sendegeraet.meister.befehl(order).ok();
+            self.sendegeraet.try_befehl_oder_dead_letter(umschlag_abbrechen.into());
+        }
+    }
+}
+
+// Antwort
+
+pub struct Antwort<I> {
+    reply_tx: mpsc::SyncSender<I>,
+}
+
+impl<I> Echo<I> for Antwort<I> {
+    fn commit_echo(self, inhalt: I) -> Result<(), EchoError<I>> {
+        self.reply_tx.send(inhalt)
+            .map_err(|mpsc::SendError(inhalt)| EchoError { inhalt, })
+    }
+}
+
+pub enum AnfrageError<B> {
+    Send(SendError<B>),
+    Cancelled,
+    Timeout,
+}
+
+// Zusage
+
+pub struct Zusage<I> {
+    zustand: Arc<Mutex<ZusageZustand<I>>>,
+}
+
+pub struct Erwartung<I> {
+    zustand: Arc<Mutex<ZusageZustand<I>>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Abgebrochen;
+
+struct ZusageZustand<I> {
+    maybe_inhalt: Option<I>,
+    maybe_waker: Option<task::Waker>,
+    is_zusage_dropped: bool,
+    is_erwartung_dropped: bool,
+}
+
+pub fn zusage<I>() -> (Zusage<I>, Erwartung<I>) {
+    let zustand = Arc::new(Mutex::new(ZusageZustand {
+        maybe_inhalt: None,
+        maybe_waker: None,
+        is_zusage_dropped: false,
+        is_erwartung_dropped: false,
+    }));
+    (Zusage { zustand: zustand.clone(), }, Erwartung { zustand, })
+}
+
+impl<I> Echo<I> for Zusage<I> {
+    fn commit_echo(self, inhalt: I) -> Result<(), EchoError<I>> {
+        let Ok(mut zustand) = self.zustand.lock() else {
+            return Err(EchoError { inhalt, });
+        };
+        if zustand.is_erwartung_dropped {
+            return Err(EchoError { inhalt, });
+        }
+        zustand.maybe_inhalt = Some(inhalt);
+        Ok(())
+    }
+}
+
+impl<I> Drop for Zusage<I> {
+    fn drop(&mut self) {
+        if let Ok(mut zustand) = self.zustand.lock() {
+            zustand.is_zusage_dropped = true;
+            if let Some(waker) = zustand.maybe_waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<I> Future for Erwartung<I> {
+    type Output = Result<I, Abgebrochen>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let Ok(mut zustand) = self.zustand.lock() else {
+            return Poll::Ready(Err(Abgebrochen));
+        };
+        if let Some(inhalt) = zustand.maybe_inhalt.take() {
+            return Poll::Ready(Ok(inhalt));
+        }
+        if zustand.is_zusage_dropped {
+            return Poll::Ready(Err(Abgebrochen));
+        }
+        zustand.maybe_waker = Some(context.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<I> Drop for Erwartung<I> {
+    fn drop(&mut self) {
+        if let Ok(mut zustand) = self.zustand.lock() {
+            zustand.is_erwartung_dropped = true;
         }
     }
 }
@@ -115,33 +307,76 @@ impl From<StreamzeugMehr> for StreamToken {
 }
 
 #[derive(Debug)]
-pub enum Streamzeug<Z> {
+pub enum Streamzeug<Z, E = Infallible> {
     NichtMehr(StreamzeugNichtMehr),
     Zeug {
         zeug: Z,
         mehr: StreamzeugMehr,
     },
+    Fehler {
+        fehler: E,
+        nicht_mehr: StreamzeugNichtMehr,
+    },
+}
+
+impl<Z> Streamzeug<Z> {
+    pub fn fehlbar<E>(self) -> Streamzeug<Z, E> {
+        match self {
+            Streamzeug::NichtMehr(nicht_mehr) =>
+                Streamzeug::NichtMehr(nicht_mehr),
+            Streamzeug::Zeug { zeug, mehr, } =>
+                Streamzeug::Zeug { zeug, mehr, },
+        }
+    }
 }
 
 pub struct StreamToken {
     stream_id: StreamId,
     cancellable: Arc<AtomicBool>,
+    credits: usize,
 }
 
 impl StreamToken {
-    pub(crate) fn new(stream_id: StreamId, cancellable: Arc<AtomicBool>) -> StreamToken {
-        StreamToken { stream_id, cancellable, }
+    pub(crate) fn new(stream_id: StreamId, cancellable: Arc<AtomicBool>, credits: usize) -> StreamToken {
+        StreamToken { stream_id, cancellable, credits, }
     }
 
     pub fn stream_id(&self) -> &StreamId {
         &self.stream_id
     }
 
+    pub fn credits(&self) -> usize {
+        self.credits
+    }
+
+    pub fn credits_aufnehmen(&mut self, stream_token: StreamToken) -> Result<(), StreamToken> {
+        if self.stream_id != stream_token.stream_id {
+            return Err(stream_token);
+        }
+        self.credits += stream_token.credits;
+        Ok(())
+    }
+
+    pub fn streamzeug_zeug_vorab<Z, E>(&mut self, zeug: Z) -> Option<Streamzeug<Z, E>> {
+        if self.credits == 0 {
+            return None;
+        }
+        self.credits -= 1;
+        // credits are replenished only with `Stream::credits_gewaehren`, so this receipt token carries none
+        let token = StreamToken::new(self.stream_id.clone(), self.cancellable.clone(), 0);
+        Some(Streamzeug::Zeug { zeug, mehr: StreamzeugMehr { token, }, })
+    }
+
     pub fn streamzeug_nicht_mehr<Z>(self) -> Streamzeug<Z> {
         self.cancellable.store(false, Ordering::SeqCst);
         Streamzeug::NichtMehr(StreamzeugNichtMehr { stream_id: self.stream_id, })
     }
 
+    pub fn streamzeug_fehler<Z, E>(self, fehler: E) -> Streamzeug<Z, E> {
+        self.cancellable.store(false, Ordering::SeqCst);
+        Streamzeug::Fehler { fehler, nicht_mehr: StreamzeugNichtMehr { stream_id: self.stream_id, }, }
+    }
+
     pub fn streamzeug_zeug<Z>(self, zeug: Z) -> Streamzeug<Z> {
         Streamzeug::Zeug { zeug, mehr: StreamzeugMehr { token: self, }, }
     }
@@ -175,8 +410,26 @@ impl<B> Stream<B> where B: From<StreamAbbrechen> {
         &self.stream_id
     }
 
-    pub fn mehr<I>(&self, inhalt: I, stream_token: StreamToken) -> Result<(), Error> where B: From<StreamMehr<I>> {
-        self.sendegeraet.meister.befehl(StreamMehr { inhalt, stream_token, }.into())
+    pub fn mehr<I>(&self, inhalt: I, stream_token: StreamToken) -> Result<(), SendError<StreamMehr<I>>> where B: From<StreamMehr<I>> {
+        self.mehr_senden(inhalt, stream_token)
+    }
+
+    pub fn credits_gewaehren<I>(&self, inhalt: I, credits: usize) -> Result<(), SendError<StreamMehr<I>>> where B: From<StreamMehr<I>> {
+        let stream_token = StreamToken::new(self.stream_id.clone(), self.cancellable.clone(), credits);
+        self.mehr_senden(inhalt, stream_token)
+    }
+
+    fn mehr_senden<I>(&self, inhalt: I, stream_token: StreamToken) -> Result<(), SendError<StreamMehr<I>>> where B: From<StreamMehr<I>> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("stream_mehr", actor = %self.sendegeraet.kennung()).entered();
+        let mut maybe_stream_mehr = Some(StreamMehr { inhalt, stream_token, });
+        self.sendegeraet.meister
+            .befehl_mit(&mut || maybe_stream_mehr.take().unwrap().into())
+            .map_err(|error| SendError {
+                order: maybe_stream_mehr.take().unwrap(),
+                error,
+                kennung: self.sendegeraet.kennung(),
+            })
     }
 }
 
@@ -184,13 +437,101 @@ impl<B> Drop for Stream<B> where B: From<StreamAbbrechen> {
     fn drop(&mut self) {
         if self.cancellable.load(Ordering::SeqCst) {
             self.sendegeraet
-                .meister
-                .befehl(StreamAbbrechen { stream_id: self.stream_id.clone(), }.into())
-                .ok();
+                .try_befehl_oder_dead_letter(StreamAbbrechen { stream_id: self.stream_id.clone(), }.into());
+        }
+    }
+}
+
+// StreamLeser
+
+pub struct StreamLeser<B, Z, F> where B: From<StreamAbbrechen> + 'static {
+    maybe_stream: Option<Stream<B>>,
+    maybe_erwartung: Option<Erwartung<Streamzeug<Z>>>,
+    make_mehr: F,
+}
+
+impl<B, Z, F, M> StreamLeser<B, Z, F>
+where B: From<StreamAbbrechen> + From<StreamMehr<M>>,
+      F: FnMut(Zusage<Streamzeug<Z>>) -> M,
+{
+    pub fn poll_naechste(&mut self, context: &mut task::Context<'_>) -> Poll<Option<Z>> {
+        let Some(erwartung) = self.maybe_erwartung.as_mut() else {
+            return Poll::Ready(None);
+        };
+        let streamzeug = match Pin::new(erwartung).poll(context) {
+            Poll::Pending =>
+                return Poll::Pending,
+            Poll::Ready(result) =>
+                result,
+        };
+        self.maybe_erwartung = None;
+        match streamzeug {
+            Ok(Streamzeug::Zeug { zeug, mehr, }) => {
+                if let Some(stream) = self.maybe_stream.as_ref() {
+                    let (zusage, erwartung) = zusage();
+                    match stream.mehr((self.make_mehr)(zusage), mehr.into()) {
+                        Ok(()) =>
+                            self.maybe_erwartung = Some(erwartung),
+                        Err(error) =>
+                            log::debug!("stream {:?} ended: {error}", stream.stream_id()),
+                    }
+                }
+                Poll::Ready(Some(zeug))
+            },
+            Ok(Streamzeug::Fehler { fehler, .. }) =>
+                match fehler {},
+            Ok(Streamzeug::NichtMehr(..)) | Err(Abgebrochen) => {
+                self.maybe_stream = None;
+                Poll::Ready(None)
+            },
+        }
+    }
+
+    pub async fn naechste(&mut self) -> Option<Z> {
+        std::future::poll_fn(|context| self.poll_naechste(context)).await
+    }
+}
+
+impl<B, Z, F, M> Iterator for StreamLeser<B, Z, F>
+where B: From<StreamAbbrechen> + From<StreamMehr<M>>,
+      F: FnMut(Zusage<Streamzeug<Z>>) -> M,
+{
+    type Item = Z;
+
+    fn next(&mut self) -> Option<Z> {
+        let waker = task::Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = task::Context::from_waker(&waker);
+        loop {
+            match self.poll_naechste(&mut context) {
+                Poll::Ready(maybe_zeug) =>
+                    return maybe_zeug,
+                Poll::Pending =>
+                    thread::park(),
+            }
         }
     }
 }
 
+#[cfg(feature = "futures")]
+impl<B, Z, F, M> futures_core::Stream for StreamLeser<B, Z, F>
+where B: From<StreamAbbrechen> + From<StreamMehr<M>>,
+      F: FnMut(Zusage<Streamzeug<Z>>) -> M + Unpin,
+{
+    type Item = Z;
+
+    fn poll_next(self: Pin<&mut Self>, context: &mut task::Context<'_>) -> Poll<Option<Z>> {
+        self.get_mut().poll_naechste(context)
+    }
+}
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
 // StreamErbauer
 
 #[derive(Clone, Default)]
@@ -198,10 +539,195 @@ pub struct StreamErbauer {
     stream_counter: Arc<AtomicUsize>,
 }
 
+// Rundfunk
+
+#[derive(Debug)]
+pub struct RundfunkZeug<Z> {
+    pub zeug: Z,
+    pub stream_id: StreamId,
+}
+
+#[derive(Debug)]
+pub struct RundfunkEnde {
+    pub stream_id: StreamId,
+}
+
+pub struct Rundfunk<Z> {
+    inner: Arc<RundfunkInner<Z>>,
+}
+
+pub struct Abonnement<Z> {
+    abonnent_id: usize,
+    rundfunk: Arc<RundfunkInner<Z>>,
+}
+
+struct RundfunkInner<Z> {
+    stream_id: StreamId,
+    abonnenten: Mutex<Vec<Abonnent<Z>>>,
+    abonnent_counter: AtomicUsize,
+    rundfunk_count: AtomicUsize,
+    cancellable: AtomicBool,
+    abbrechen: Box<dyn Fn(StreamAbbrechen) + Send + Sync + 'static>,
+}
+
+struct Abonnent<Z> {
+    abonnent_id: usize,
+    zustellen: Zustellen<Z>,
+    beenden: Beenden,
+}
+
+type Zustellen<Z> = Arc<dyn Fn(&Z, &StreamId) -> Result<(), Error> + Send + Sync + 'static>;
+
+type Beenden = Arc<dyn Fn(&StreamId) + Send + Sync + 'static>;
+
+impl<Z> Clone for Abonnent<Z> {
+    fn clone(&self) -> Self {
+        Abonnent {
+            abonnent_id: self.abonnent_id,
+            zustellen: self.zustellen.clone(),
+            beenden: self.beenden.clone(),
+        }
+    }
+}
+
+impl<Z> Rundfunk<Z> {
+    pub fn stream_id(&self) -> &StreamId {
+        &self.inner.stream_id
+    }
+
+    pub fn abonnenten_count(&self) -> usize {
+        self.inner.abonnenten.lock()
+            .map(|abonnenten| abonnenten.len())
+            .unwrap_or(0)
+    }
+
+    pub fn abonnieren<B>(&self, sendegeraet: &Sendegeraet<B>) -> Option<Abonnement<Z>>
+    where B: From<RundfunkZeug<Z>> + From<RundfunkEnde> + Send + 'static,
+          Z: Clone,
+    {
+        let abonnent_id = self.inner.abonnent_counter.fetch_add(1, Ordering::Relaxed);
+        let zustellen_sendegeraet = sendegeraet.clone();
+        let beenden_sendegeraet = sendegeraet.clone();
+        let abonnent = Abonnent {
+            abonnent_id,
+            zustellen: Arc::new(move |zeug: &Z, stream_id: &StreamId| {
+                let rundfunk_zeug = RundfunkZeug { zeug: zeug.clone(), stream_id: stream_id.clone(), };
+                zustellen_sendegeraet.try_befehl(rundfunk_zeug.into())
+                    .map_err(|send_error| send_error.error)
+            }),
+            beenden: Arc::new(move |stream_id: &StreamId| {
+                // the end marker must not get lost: a full mailbox postpones it instead
+                let rundfunk_ende = Arc::new(Mutex::new(Some(RundfunkEnde { stream_id: stream_id.clone(), })));
+                beenden_sendegeraet.zeitgeber_zustellen(rundfunk_ende, |rundfunk_ende| rundfunk_ende.into());
+            }),
+        };
+        let mut abonnenten = self.inner.abonnenten.lock().ok()?;
+        if !self.inner.cancellable.load(Ordering::SeqCst) {
+            // producer has already got StreamAbbrechen for this stream
+            return None;
+        }
+        abonnenten.push(abonnent);
+        Some(Abonnement { abonnent_id, rundfunk: self.inner.clone(), })
+    }
+
+    pub fn senden(&self, zeug: Z) -> usize {
+        let Ok(abonnenten) = self.inner.abonnenten.lock() else {
+            return 0;
+        };
+        let snapshot = abonnenten.clone();
+        drop(abonnenten);
+
+        let stream_id = &self.inner.stream_id;
+        let mut zugestellt = 0;
+        let mut verloren = Vec::new();
+        for abonnent in &snapshot {
+            match (abonnent.zustellen)(&zeug, stream_id) {
+                Ok(()) =>
+                    zugestellt += 1,
+                Err(Error::Full) =>
+                    // backpressure: a subscriber with a full mailbox misses this item, producer never blocks
+                    (),
+                Err(..) =>
+                    verloren.push(abonnent.abonnent_id),
+            }
+        }
+        if !verloren.is_empty() {
+            // unreachable subscribers are considered left
+            self.inner.entfernen(|abonnent_id| verloren.contains(&abonnent_id));
+        }
+        zugestellt
+    }
+}
+
+impl<Z> RundfunkInner<Z> {
+    fn entfernen<P>(&self, pred: P) where P: Fn(usize) -> bool {
+        let Ok(mut abonnenten) = self.abonnenten.lock() else {
+            return;
+        };
+        let abonnenten_count_before = abonnenten.len();
+        abonnenten.retain(|abonnent| !pred(abonnent.abonnent_id));
+        // cancel only when the last subscriber leaves, not when there were none at all
+        let is_last = abonnenten_count_before > 0 && abonnenten.is_empty()
+            && self.cancellable.swap(false, Ordering::SeqCst);
+        drop(abonnenten);
+        if is_last {
+            (self.abbrechen)(StreamAbbrechen { stream_id: self.stream_id.clone(), });
+        }
+    }
+
+    fn beenden(&self) {
+        let Ok(mut locked_abonnenten) = self.abonnenten.lock() else {
+            return;
+        };
+        // producer is gone: nobody is left to be cancelled and late subscribers are rejected
+        self.cancellable.store(false, Ordering::SeqCst);
+        let abonnenten = mem::take(&mut *locked_abonnenten);
+        drop(locked_abonnenten);
+        for abonnent in abonnenten {
+            (abonnent.beenden)(&self.stream_id);
+        }
+    }
+}
+
+impl<Z> Abonnement<Z> {
+    pub fn stream_id(&self) -> &StreamId {
+        &self.rundfunk.stream_id
+    }
+}
+
+impl<Z> Drop for Abonnement<Z> {
+    fn drop(&mut self) {
+        self.rundfunk.entfernen(|abonnent_id| abonnent_id == self.abonnent_id);
+    }
+}
+
+impl<Z> Clone for Rundfunk<Z> {
+    fn clone(&self) -> Self {
+        self.inner.rundfunk_count.fetch_add(1, Ordering::Relaxed);
+        Rundfunk {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Z> Drop for Rundfunk<Z> {
+    fn drop(&mut self) {
+        if self.inner.rundfunk_count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.inner.beenden();
+        }
+    }
+}
+
 // SendegeraetMeister
 
 trait SendegeraetMeister<B> where Self: Send + Sync + 'static {
-    fn befehl(&self, order: B) -> Result<(), Error>;
+    fn befehl_mit(&self, make_order: &mut dyn FnMut() -> B) -> Result<(), Error>;
+
+    fn try_befehl_mit(&self, make_order: &mut dyn FnMut() -> B) -> Result<(), Error>;
+
+    fn dead_letter(&self, order: B);
+
+    fn kennung(&self) -> Kennung;
 }
 
 // SendegeraetInner
@@ -209,6 +735,7 @@ trait SendegeraetMeister<B> where Self: Send + Sync + 'static {
 struct SendegeraetInner<W, B, J> {
     schwach_meister: SchwachMeister<W, B>,
     thread_pool: edeltraud::Handle<J>,
+    dead_letters: Arc<OnceLock<DeadLetterSink<B>>>,
 }
 
 impl<W, B, J> SendegeraetMeister<B> for SendegeraetInner<W, B, J>
@@ -216,8 +743,20 @@ where J: From<SklaveJob<W, B>> + Send + 'static,
       W: Send + 'static,
       B: Send + 'static,
 {
-    fn befehl(&self, order: B) -> Result<(), Error> {
-        self.schwach_meister.befehl(order, &self.thread_pool)
+    fn befehl_mit(&self, make_order: &mut dyn FnMut() -> B) -> Result<(), Error> {
+        self.schwach_meister.befehl_mit(make_order, &self.thread_pool)
+    }
+
+    fn try_befehl_mit(&self, make_order: &mut dyn FnMut() -> B) -> Result<(), Error> {
+        self.schwach_meister.try_befehl_mit(make_order, &self.thread_pool)
+    }
+
+    fn dead_letter(&self, order: B) {
+        dead_letter(&self.dead_letters, || self.schwach_meister.kennung(), order);
+    }
+
+    fn kennung(&self) -> Kennung {
+        self.schwach_meister.kennung()
     }
 }
 
@@ -238,10 +777,9 @@ impl<B> Sendegeraet<B> where B: Send + 'static {
     {
         let inner =
             SendegeraetInner {
-                schwach_meister: SchwachMeister {
-                    maybe_inner: Arc::downgrade(&meister.inner),
-                },
+                schwach_meister: meister.downgrade(),
                 thread_pool,
+                dead_letters: meister.inner.dead_letters.clone(),
             };
         Sendegeraet {
             meister: Arc::new(inner),
@@ -251,11 +789,50 @@ impl<B> Sendegeraet<B> where B: Send + 'static {
     pub fn rueckkopplung<S>(&self, stamp: S) -> Rueckkopplung<B, S> where B: From<UmschlagAbbrechen<S>> {
         Rueckkopplung {
             sendegeraet: self.clone(),
-            maybe_stamp: Some(stamp),
+            maybe_stamp: Stempel::Eigen(Some(stamp)),
+        }
+    }
+
+    pub fn rueckkopplung_mit_frist<S>(&self, stamp: S, timeout: Duration) -> Rueckkopplung<B, S>
+    where B: From<UmschlagAbbrechen<S>>,
+          B: From<UmschlagFristAbgelaufen<S>>,
+          S: Send + 'static,
+    {
+        let maybe_stamp = Arc::new(Mutex::new(Some(stamp)));
+        let sendegeraet = self.clone();
+        let fire_maybe_stamp = maybe_stamp.clone();
+        let aktion = Box::new(move || {
+            sendegeraet.zeitgeber_zustellen(fire_maybe_stamp, |stamp| UmschlagFristAbgelaufen { stamp, }.into());
+        });
+        let planung = zeitgeber::Planung { frist: Instant::now() + timeout, aktion, };
+        let maybe_abbrecher = match zeitgeber::planen(planung) {
+            Ok(abbrecher) =>
+                Some(abbrecher),
+            Err(error) => {
+                log::error!("failed to schedule rueckkopplung frist for {}: {error:?}", self.kennung());
+                None
+            },
+        };
+        Rueckkopplung {
+            sendegeraet: self.clone(),
+            maybe_stamp: Stempel::MitFrist { maybe_stamp, maybe_abbrecher, },
         }
     }
 
-    pub fn stream_starten<I>(&self, stream_erbauer: &StreamErbauer, inhalt: I) -> Result<Stream<B>, Error>
+    pub fn stream_starten<I>(&self, stream_erbauer: &StreamErbauer, inhalt: I) -> Result<Stream<B>, SendError<I>>
+    where B: From<StreamStarten<I>>,
+          B: From<StreamAbbrechen>,
+    {
+        self.stream_starten_mit_credits(stream_erbauer, inhalt, 1)
+    }
+
+    pub fn stream_starten_mit_credits<I>(
+        &self,
+        stream_erbauer: &StreamErbauer,
+        inhalt: I,
+        credits: usize,
+    )
+        -> Result<Stream<B>, SendError<I>>
     where B: From<StreamStarten<I>>,
           B: From<StreamAbbrechen>,
     {
@@ -267,33 +844,157 @@ impl<B> Sendegeraet<B> where B: Send + 'static {
         let stream_token = StreamToken::new(
             stream_id.clone(),
             cancellable.clone(),
+            credits,
         );
-        let order = StreamStarten { inhalt, stream_token, }.into();
-        self.meister.befehl(order)?;
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("stream_starten", actor = %self.kennung()).entered();
+        let mut maybe_stream_starten = Some(StreamStarten { inhalt, stream_token, });
+        self.meister
+            .befehl_mit(&mut || maybe_stream_starten.take().unwrap().into())
+            .map_err(|error| SendError {
+                order: maybe_stream_starten.take().unwrap().inhalt,
+                error,
+                kennung: self.kennung(),
+            })?;
 
         Ok(Stream { sendegeraet: self.clone(), stream_id, cancellable, })
     }
-}
 
-impl<B> Clone for Sendegeraet<B> {
-    fn clone(&self) -> Self {
-        Self {
-            meister: self.meister.clone(),
+    pub fn rundfunk_starten<Z>(&self, stream_erbauer: &StreamErbauer) -> Rundfunk<Z> where B: From<StreamAbbrechen> {
+        let id = stream_erbauer
+            .stream_counter
+            .fetch_add(1, Ordering::Relaxed);
+        let sendegeraet = self.clone();
+        Rundfunk {
+            inner: Arc::new(RundfunkInner {
+                stream_id: StreamId { id, },
+                abonnenten: Mutex::new(Vec::new()),
+                abonnent_counter: AtomicUsize::new(0),
+                rundfunk_count: AtomicUsize::new(1),
+                cancellable: AtomicBool::new(true),
+                abbrechen: Box::new(move |stream_abbrechen| {
+                    sendegeraet.try_befehl_oder_dead_letter(stream_abbrechen.into());
+                }),
+            }),
         }
     }
+
+    pub fn stream_lesen<I, M, Z, S, F>(
+        &self,
+        stream_erbauer: &StreamErbauer,
+        make_starten: S,
+        make_mehr: F,
+    )
+        -> Result<StreamLeser<B, Z, F>, SendError<I>>
+    where B: From<StreamStarten<I>>,
+          B: From<StreamMehr<M>>,
+          B: From<StreamAbbrechen>,
+          S: FnOnce(Zusage<Streamzeug<Z>>) -> I,
+          F: FnMut(Zusage<Streamzeug<Z>>) -> M,
+    {
+        let (zusage, erwartung) = zusage();
+        let stream = self.stream_starten(stream_erbauer, make_starten(zusage))?;
+        Ok(StreamLeser {
+            maybe_stream: Some(stream),
+            maybe_erwartung: Some(erwartung),
+            make_mehr,
+        })
+    }
 }
 
-// WeakMeister
+impl<B> Sendegeraet<B> where B: 'static {
+    pub fn befehl(&self, order: B) -> Result<(), SendError<B>> {
+        let mut maybe_order = Some(order);
+        self.meister
+            .befehl_mit(&mut || maybe_order.take().unwrap())
+            .map_err(|error| SendError { order: maybe_order.take().unwrap(), error, kennung: self.kennung(), })
+    }
+
+    pub fn try_befehl(&self, order: B) -> Result<(), SendError<B>> {
+        let mut maybe_order = Some(order);
+        self.meister
+            .try_befehl_mit(&mut || maybe_order.take().unwrap())
+            .map_err(|error| SendError { order: maybe_order.take().unwrap(), error, kennung: self.kennung(), })
+    }
+
+    pub fn actor_id(&self) -> ActorId {
+        self.meister.kennung().actor_id
+    }
+
+    pub fn kennung(&self) -> Kennung {
+        self.meister.kennung()
+    }
+
+    pub fn anfrage_blocking<I, F>(&self, make_order: F, timeout: Duration) -> Result<I, AnfrageError<B>>
+    where F: FnOnce(Antwort<I>) -> B,
+    {
+        let (reply_tx, reply_rx) = mpsc::sync_channel(1);
+        self.befehl(make_order(Antwort { reply_tx, }))
+            .map_err(AnfrageError::Send)?;
+        reply_rx.recv_timeout(timeout)
+            .map_err(|error| match error {
+                mpsc::RecvTimeoutError::Disconnected =>
+                    AnfrageError::Cancelled,
+                mpsc::RecvTimeoutError::Timeout =>
+                    AnfrageError::Timeout,
+            })
+    }
+
+    pub(crate) fn try_befehl_oder_dead_letter(&self, order: B) {
+        if let Err(SendError { order, .. }) = self.try_befehl(order) {
+            self.meister.dead_letter(order);
+        }
+    }
 
-struct SchwachMeister<W, B> {
-    maybe_inner: Weak<ArbeitssklaveInner<W, B>>,
+    // timer actions run on the single zeitgeber thread: they never block on a full mailbox,
+    // the delivery is postponed by a tick instead and dead lettered only when the sklave is gone
+    pub(crate) fn zeitgeber_zustellen<T>(&self, maybe_wert: Arc<Mutex<Option<T>>>, make_order: fn(T) -> B)
+    where T: Send + 'static,
+    {
+        let Ok(mut wert_lock) = maybe_wert.lock() else {
+            return;
+        };
+        let Some(wert) = wert_lock.take() else {
+            return;
+        };
+        let mut maybe_wert_lokal = Some(wert);
+        match self.meister.try_befehl_mit(&mut || make_order(maybe_wert_lokal.take().unwrap())) {
+            Ok(()) =>
+                (),
+            Err(Error::Full) => {
+                *wert_lock = maybe_wert_lokal.take();
+                drop(wert_lock);
+                let sendegeraet = self.clone();
+                let nochmal_maybe_wert = maybe_wert.clone();
+                let planung = zeitgeber::Planung {
+                    frist: Instant::now() + zeitgeber::TICK,
+                    aktion: Box::new(move || sendegeraet.zeitgeber_zustellen(nochmal_maybe_wert, make_order)),
+                };
+                if let Err(error) = zeitgeber::planen(planung) {
+                    log::error!("failed to reschedule timer delivery for {}: {error:?}", self.kennung());
+                    let maybe_wert = maybe_wert.lock()
+                        .ok()
+                        .and_then(|mut maybe_wert| maybe_wert.take());
+                    if let Some(wert) = maybe_wert {
+                        self.meister.dead_letter(make_order(wert));
+                    }
+                }
+            },
+            Err(..) => {
+                drop(wert_lock);
+                if let Some(wert) = maybe_wert_lokal.take() {
+                    self.meister.dead_letter(make_order(wert));
+                }
+            },
+        }
+    }
 }
 
-impl<W, B> SchwachMeister<W, B> {
-    fn befehl<J>(&self, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error> where J: From<SklaveJob<W, B>> {
-        let inner = self.maybe_inner.upgrade()
-            .ok_or(Error::Terminated)?;
-        inner.befehl(order, thread_pool)
+impl<B> Clone for Sendegeraet<B> {
+    fn clone(&self) -> Self {
+        Self {
+            meister: self.meister.clone(),
+        }
     }
 }
 
@@ -311,10 +1012,100 @@ where B: From<UmschlagAbbrechen<S>>,
     }
 }
 
+impl<I> fmt::Debug for Antwort<I> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Antwort")
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<I> fmt::Debug for Zusage<I> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Zusage")
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<I> fmt::Debug for Erwartung<I> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Erwartung")
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<B> fmt::Debug for AnfrageError<B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnfrageError::Send(send_error) =>
+                fmt.debug_tuple("Send").field(send_error).finish(),
+            AnfrageError::Cancelled =>
+                write!(fmt, "Cancelled"),
+            AnfrageError::Timeout =>
+                write!(fmt, "Timeout"),
+        }
+    }
+}
+
+impl<B, Z, F> fmt::Debug for StreamLeser<B, Z, F> where B: From<StreamAbbrechen> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("StreamLeser")
+            .field("stream_id", &self.maybe_stream.as_ref().map(Stream::stream_id))
+            .finish()
+    }
+}
+
+impl<Z> fmt::Debug for Rundfunk<Z> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Rundfunk")
+            .field("stream_id", &self.inner.stream_id)
+            .field("abonnenten_count", &self.abonnenten_count())
+            .finish()
+    }
+}
+
+impl<Z> fmt::Debug for Abonnement<Z> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Abonnement")
+            .field("stream_id", &self.rundfunk.stream_id)
+            .field("abonnent_id", &self.abonnent_id)
+            .finish()
+    }
+}
+
 impl fmt::Debug for StreamToken {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("StreamToken")
             .field("stream_id", &self.stream_id)
+            .field("credits", &self.credits)
+            .finish()
+    }
+}
+
+impl<I> fmt::Debug for EchoError<I> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("EchoError")
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<I, S> fmt::Debug for CommitError<I, S> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("CommitError")
+            .field("error", &self.error)
+            .field("kennung", &self.kennung)
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<B> fmt::Debug for Sendegeraet<B> where B: 'static {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Sendegeraet")
+            .field("kennung", &self.meister.kennung())
             .finish()
     }
 }
@@ -1,4 +1,13 @@
 use std::{
+    fmt,
+    mem,
+    any::{
+        Any,
+    },
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
     ops::{
         Deref,
         DerefMut,
@@ -8,7 +17,11 @@ use std::{
     },
     sync::{
         atomic,
+        mpsc,
         Arc,
+        Weak,
+        Mutex,
+        OnceLock,
     },
     collections::{
         VecDeque,
@@ -17,7 +30,17 @@ use std::{
 
 pub mod ewig;
 pub mod komm;
+pub mod arbeit;
 pub mod utils;
+pub mod aufseher;
+pub mod verzeichnis;
+pub mod zeitgeber;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "derive")]
+pub use arbeitssklave_derive::ArbeitssklaveJob;
 
 #[cfg(test)]
 mod tests;
@@ -38,36 +61,162 @@ impl<W, B> Clone for Meister<W, B> {
     }
 }
 
+pub struct SchwachMeister<W, B> {
+    maybe_inner: Weak<Inner<W, B>>,
+    actor_id: ActorId,
+    name: Arc<OnceLock<String>>,
+}
+
+impl<W, B> Clone for SchwachMeister<W, B> {
+    fn clone(&self) -> Self {
+        SchwachMeister {
+            maybe_inner: self.maybe_inner.clone(),
+            actor_id: self.actor_id,
+            name: self.name.clone(),
+        }
+    }
+}
+
 pub struct SklaveJob<W, B> {
     inner: Arc<Inner<W, B>>,
     rasten_mark: bool,
+    #[cfg(feature = "tracing")]
+    befehl_span: Option<tracing::Span>,
 }
 
 impl<W, B> SklaveJob<W, B> {
     fn new(inner: Arc<Inner<W, B>>) -> Self {
-        Self { inner, rasten_mark: false, }
+        Self {
+            inner,
+            rasten_mark: false,
+            #[cfg(feature = "tracing")]
+            befehl_span: None,
+        }
     }
 }
 
 struct Sklavenwelt<W, B> {
     sklavenwelt: W,
-    taken_orders: VecDeque<B>,
+    taken_orders: VecDeque<Auftrag<B>>,
+    is_started: bool,
+    #[cfg(feature = "metrics")]
+    batch_started_at: Option<std::time::Instant>,
 }
 
 impl<W, B> Sklavenwelt<W, B> {
     fn new(sklavenwelt: W) -> Self {
-        Self { sklavenwelt, taken_orders: VecDeque::new(), }
+        Self {
+            sklavenwelt,
+            taken_orders: VecDeque::new(),
+            is_started: false,
+            #[cfg(feature = "metrics")]
+            batch_started_at: None,
+        }
+    }
+}
+
+struct Auftrag<B> {
+    order: B,
+    #[cfg(feature = "metrics")]
+    enqueued_at: std::time::Instant,
+    #[cfg(feature = "tracing")]
+    sender_span: tracing::Span,
+}
+
+impl<B> Auftrag<B> {
+    fn new(order: B) -> Self {
+        Self {
+            order,
+            #[cfg(feature = "metrics")]
+            enqueued_at: std::time::Instant::now(),
+            #[cfg(feature = "tracing")]
+            sender_span: tracing::Span::current(),
+        }
     }
 }
 
 struct Inner<W, B> {
-    orders: crossbeam::queue::SegQueue<B>,
+    orders: crossbeam::queue::SegQueue<Auftrag<B>>,
+    capacity: Option<usize>,
     touch_tag: TouchTag,
     sklavenwelt: UnsafeCell<Option<Sklavenwelt<W, B>>>,
+    beenden_tx: Mutex<Option<mpsc::SyncSender<W>>>,
+    panic_message: Mutex<Option<String>>,
+    actor_id: ActorId,
+    name: Arc<OnceLock<String>>,
+    dead_letters: Arc<OnceLock<DeadLetterSink<B>>>,
+    aufsicht: OnceLock<aufseher::Aufsicht<W>>,
+    peitsche: OnceLock<SklaveJobSpawn<W, B>>,
+    arbeiter_thread: atomic::AtomicUsize,
+    neustart_mark: atomic::AtomicBool,
+    beendet_hooks: Mutex<Vec<BeendetHook>>,
+    #[cfg(feature = "metrics")]
+    metrics: Arc<metrics::Metrics>,
+}
+
+type BeendetHook = Box<dyn FnOnce() + Send + 'static>;
+
+type SklaveJobSpawn<W, B> = Box<dyn Fn(SklaveJob<W, B>) -> Result<(), edeltraud::SpawnError> + Send + Sync + 'static>;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ActorId {
+    id: usize,
+}
+
+impl ActorId {
+    fn next() -> ActorId {
+        static ACTOR_COUNTER: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+        ActorId { id: ACTOR_COUNTER.fetch_add(1, atomic::Ordering::Relaxed), }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Kennung {
+    pub actor_id: ActorId,
+    pub name: Option<String>,
+}
+
+impl Kennung {
+    fn new(actor_id: ActorId, name: &OnceLock<String>) -> Kennung {
+        Kennung { actor_id, name: name.get().cloned(), }
+    }
+}
+
+impl fmt::Display for Kennung {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) =>
+                write!(fmt, "{name}#{}", self.actor_id.id),
+            None =>
+                write!(fmt, "sklave#{}", self.actor_id.id),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeadLetter<B> {
+    pub kennung: Kennung,
+    pub order: B,
+}
+
+type DeadLetterSink<B> = Box<dyn Fn(DeadLetter<B>) + Send + Sync + 'static>;
+
+fn dead_letter<B, K>(dead_letters: &OnceLock<DeadLetterSink<B>>, kennung: K, order: B) where K: FnOnce() -> Kennung {
+    if let Some(sink) = dead_letters.get() {
+        sink(DeadLetter { kennung: kennung(), order, });
+    }
 }
 
 unsafe impl<W, B> Sync for Inner<W, B> { }
 
+fn thread_nummer() -> usize {
+    static THREAD_COUNTER: atomic::AtomicUsize = atomic::AtomicUsize::new(1);
+    thread_local! {
+        static THREAD_NUMMER: usize = THREAD_COUNTER.fetch_add(1, atomic::Ordering::Relaxed);
+    }
+    THREAD_NUMMER.with(|nummer| *nummer)
+}
+
 fn reach_sklavenwelt<W, B>(inner: &Arc<Inner<W, B>>) -> &Option<Sklavenwelt<W, B>> {
     unsafe { &*inner.sklavenwelt.get() }
 }
@@ -79,8 +228,64 @@ fn reach_sklavenwelt_mut<W, B>(inner: &mut Arc<Inner<W, B>>) -> &mut Option<Skla
 #[derive(Debug)]
 pub enum Error {
     Edeltraud(edeltraud::SpawnError),
-    Terminated,
+    Terminated(TerminationReason),
+    SklavenweltMissing,
+    BeendenReentrant,
+    Full,
+    Panicked(String),
+}
+
+pub struct SendError<B> {
+    pub order: B,
+    pub error: Error,
+    pub kennung: Kennung,
+}
+
+impl<B> From<SendError<B>> for Error {
+    fn from(send_error: SendError<B>) -> Error {
+        send_error.error
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TerminationReason {
+    JobDropped,
+    Shutdown,
+    Panicked,
     SklavenweltDropped,
+    ThreadPoolSpawn,
+}
+
+impl TerminationReason {
+    fn encode(maybe_reason: Option<TerminationReason>) -> u8 {
+        match maybe_reason {
+            None => 0,
+            Some(TerminationReason::JobDropped) => 1,
+            Some(TerminationReason::Shutdown) => 2,
+            Some(TerminationReason::Panicked) => 3,
+            Some(TerminationReason::SklavenweltDropped) => 4,
+            Some(TerminationReason::ThreadPoolSpawn) => 5,
+        }
+    }
+
+    fn decode(code: u8) -> Option<TerminationReason> {
+        match code {
+            1 => Some(TerminationReason::JobDropped),
+            2 => Some(TerminationReason::Shutdown),
+            3 => Some(TerminationReason::Panicked),
+            4 => Some(TerminationReason::SklavenweltDropped),
+            5 => Some(TerminationReason::ThreadPoolSpawn),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Status {
+    pub orders_count: usize,
+    pub is_resting: bool,
+    pub is_shutdown: bool,
+    pub terminated: Option<TerminationReason>,
 }
 
 struct TouchTag {
@@ -96,7 +301,8 @@ impl Default for TouchTag {
 }
 
 struct TouchTagDecoded {
-    is_terminated: bool,
+    is_shutdown: bool,
+    terminated: Option<TerminationReason>,
     is_ready: bool,
     orders_count: usize,
 }
@@ -104,7 +310,9 @@ struct TouchTagDecoded {
 impl TouchTag {
     const ORDERS_COUNT_MASK: u64 = u32::MAX as u64;
     const READY_BIT: u64 = Self::ORDERS_COUNT_MASK.wrapping_add(1);
-    const TERMINATED_BIT: u64 = Self::READY_BIT.wrapping_shl(1);
+    const SHUTDOWN_BIT: u64 = Self::READY_BIT.wrapping_shl(1);
+    const TERMINATED_SHIFT: u32 = Self::SHUTDOWN_BIT.trailing_zeros() + 1;
+    const TERMINATED_MASK: u64 = 0b111 << Self::TERMINATED_SHIFT;
 
     fn load(&self) -> u64 {
         self.tag.load(atomic::Ordering::Relaxed)
@@ -123,7 +331,10 @@ impl TouchTag {
 
     fn decompose(tag: u64) -> TouchTagDecoded {
         TouchTagDecoded {
-            is_terminated: tag & Self::TERMINATED_BIT != 0,
+            is_shutdown: tag & Self::SHUTDOWN_BIT != 0,
+            terminated: TerminationReason::decode(
+                ((tag & Self::TERMINATED_MASK) >> Self::TERMINATED_SHIFT) as u8,
+            ),
             is_ready: tag & Self::READY_BIT != 0,
             orders_count: (tag & Self::ORDERS_COUNT_MASK) as usize,
         }
@@ -134,9 +345,10 @@ impl TouchTag {
         if decoded.is_ready {
             tag |= Self::READY_BIT;
         }
-        if decoded.is_terminated {
-            tag |= Self::TERMINATED_BIT;
+        if decoded.is_shutdown {
+            tag |= Self::SHUTDOWN_BIT;
         }
+        tag |= (TerminationReason::encode(decoded.terminated) as u64) << Self::TERMINATED_SHIFT;
         tag
     }
 }
@@ -149,15 +361,60 @@ impl<W, B> Default for Freie<W, B> {
 
 impl<W, B> Freie<W, B> {
     pub fn new() -> Self {
+        Self::with_maybe_capacity(None)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_maybe_capacity(Some(capacity))
+    }
+
+    fn with_maybe_capacity(capacity: Option<usize>) -> Self {
+        let actor_id = ActorId::next();
+        let name = Arc::new(OnceLock::new());
         Self {
             inner: Arc::new(Inner {
                 orders: crossbeam::queue::SegQueue::new(),
+                capacity,
                 touch_tag: TouchTag::default(),
                 sklavenwelt: UnsafeCell::new(None),
+                beenden_tx: Mutex::new(None),
+                panic_message: Mutex::new(None),
+                actor_id,
+                name: name.clone(),
+                dead_letters: Arc::new(OnceLock::new()),
+                aufsicht: OnceLock::new(),
+                peitsche: OnceLock::new(),
+                arbeiter_thread: atomic::AtomicUsize::new(0),
+                neustart_mark: atomic::AtomicBool::new(false),
+                beendet_hooks: Mutex::new(Vec::new()),
+                #[cfg(feature = "metrics")]
+                metrics: Arc::new(metrics::Metrics::new(actor_id, name.clone())),
             }),
         }
     }
 
+    pub fn with_dead_letters<F>(self, sink: F) -> Self where F: Fn(DeadLetter<B>) + Send + Sync + 'static {
+        if self.inner.dead_letters.set(Box::new(sink)).is_err() {
+            log::warn!("dead letters sink is already installed for {}, ignoring", self.inner.kennung());
+        }
+        self
+    }
+
+    pub fn with_name<N>(self, name: N) -> Self where N: Into<String> {
+        if let Err(name) = self.inner.name.set(name.into()) {
+            log::warn!("name is already set for {}, ignoring {name:?}", self.inner.kennung());
+        }
+        self
+    }
+
+    pub fn actor_id(&self) -> ActorId {
+        self.inner.actor_id
+    }
+
+    pub fn kennung(&self) -> Kennung {
+        self.inner.kennung()
+    }
+
     pub fn meister(&self) -> Meister<W, B> {
         Meister { inner: self.inner.clone(), }
     }
@@ -168,10 +425,16 @@ impl<W, B> Freie<W, B> {
         thread_pool: &edeltraud::Handle<J>,
     )
         -> Result<Meister<W, B>, Error>
-    where J: From<SklaveJob<W, B>>,
+    where J: From<SklaveJob<W, B>> + Send + 'static,
     {
         *reach_sklavenwelt_mut(&mut self.inner) =
             Some(Sklavenwelt::new(sklavenwelt));
+        let peitsche_thread_pool = thread_pool.clone();
+        let peitsche: SklaveJobSpawn<W, B> =
+            Box::new(move |sklave_job| edeltraud::job(&peitsche_thread_pool, sklave_job));
+        if self.inner.peitsche.set(peitsche).is_err() {
+            unreachable!("peitsche is installed only once as freie is consumed");
+        }
 
         let meister = Meister { inner: self.inner, };
         meister.inner.whip(thread_pool)?;
@@ -180,62 +443,451 @@ impl<W, B> Freie<W, B> {
 }
 
 impl<W, B> Inner<W, B> {
-    fn befehl<J>(self: &Arc<Self>, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error> where J: From<SklaveJob<W, B>> {
+    fn befehl_mit<J, F>(self: &Arc<Self>, make_order: F, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error>
+    where J: From<SklaveJob<W, B>>,
+          F: FnOnce() -> B,
+    {
+        let is_ready = self.touch_orders_warten(1)?;
+        if is_ready {
+            self.whip(thread_pool)?;
+        }
+
+        self.orders.push(Auftrag::new(make_order()));
+        #[cfg(feature = "metrics")]
+        self.metrics.orders_enqueued(1);
+        Ok(())
+    }
+
+    fn try_befehl_mit<J, F>(self: &Arc<Self>, make_order: F, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error>
+    where J: From<SklaveJob<W, B>>,
+          F: FnOnce() -> B,
+    {
+        let is_ready = self.touch_orders(1)?;
+        if is_ready {
+            self.whip(thread_pool)?;
+        }
+
+        self.orders.push(Auftrag::new(make_order()));
+        #[cfg(feature = "metrics")]
+        self.metrics.orders_enqueued(1);
+        Ok(())
+    }
+
+    fn befehle<J, I>(self: &Arc<Self>, orders: I, thread_pool: &edeltraud::Handle<J>) -> Result<(), SendError<Vec<B>>>
+    where J: From<SklaveJob<W, B>>,
+          I: IntoIterator<Item = B>,
+    {
+        let orders: Vec<B> = orders.into_iter().collect();
+        if orders.is_empty() {
+            return Ok(());
+        }
+        let is_ready = match self.touch_orders_warten(orders.len()) {
+            Ok(is_ready) =>
+                is_ready,
+            Err(error) =>
+                return Err(SendError { order: orders, error, kennung: self.kennung(), }),
+        };
+        if is_ready && let Err(error) = self.whip(thread_pool) {
+            return Err(SendError { order: orders, error, kennung: self.kennung(), });
+        }
+
+        #[cfg(feature = "metrics")]
+        self.metrics.orders_enqueued(orders.len());
+        for order in orders {
+            self.orders.push(Auftrag::new(order));
+        }
+        Ok(())
+    }
+
+    fn touch_orders_warten(&self, count: usize) -> Result<bool, Error> {
+        if self.capacity.is_some_and(|capacity| count > capacity) {
+            return Err(Error::Full);
+        }
+        let backoff = crossbeam::utils::Backoff::new();
+        loop {
+            match self.touch_orders(count) {
+                Err(Error::Full) if self.arbeiter_thread.load(atomic::Ordering::Relaxed) == thread_nummer() =>
+                    // called from within own sklave job: nobody else is going to drain the queue
+                    return Err(Error::Full),
+                Err(Error::Full) =>
+                    backoff.snooze(),
+                other_result =>
+                    return other_result,
+            }
+        }
+    }
+
+    fn touch_orders(&self, count: usize) -> Result<bool, Error> {
         let mut prev_tag = self.touch_tag.load();
         loop {
             let decoded = TouchTag::decompose(prev_tag);
-            if decoded.is_terminated {
-                return Err(Error::Terminated);
+            if let Some(reason) = decoded.terminated {
+                return Err(self.terminated_error(reason));
+            }
+            if decoded.is_shutdown {
+                return Err(Error::Terminated(TerminationReason::Shutdown));
+            }
+            if let Some(capacity) = self.capacity
+                && decoded.orders_count + count > capacity
+            {
+                return Err(Error::Full);
             }
 
             let new_tag = TouchTag::compose(TouchTagDecoded {
                 is_ready: false,
-                orders_count: decoded.orders_count + 1,
+                orders_count: decoded.orders_count + count,
                 ..decoded
             });
             if let Err(changed_tag) = self.touch_tag.try_set(prev_tag, new_tag) {
                 prev_tag = changed_tag;
                 continue;
             }
+            return Ok(decoded.is_ready);
+        }
+    }
+
+    fn beenden(self: &Arc<Self>) -> Result<W, Error> {
+        if self.peitsche.get().is_none() {
+            // not versklaven yet: there is no job which could ever hand sklavenwelt over
+            return Err(Error::SklavenweltMissing);
+        }
+        if self.arbeiter_thread.load(atomic::Ordering::Relaxed) == thread_nummer() {
+            // called from within own sklave job: waiting for that job would deadlock
+            return Err(Error::BeendenReentrant);
+        }
+        let (beenden_tx, beenden_rx) = mpsc::sync_channel(1);
+        match self.beenden_tx.lock() {
+            Ok(mut locked_beenden_tx) if locked_beenden_tx.is_none() =>
+                *locked_beenden_tx = Some(beenden_tx),
+            Ok(..) | Err(..) =>
+                return Err(Error::Terminated(TerminationReason::Shutdown)),
+        }
+
+        let mut prev_tag = self.touch_tag.load();
+        loop {
+            let decoded = TouchTag::decompose(prev_tag);
+            if let Some(reason) = decoded.terminated {
+                self.drop_beenden_tx();
+                return Err(self.terminated_error(reason));
+            }
+
+            let new_tag = TouchTag::compose(TouchTagDecoded {
+                is_shutdown: true,
+                is_ready: false,
+                ..decoded
+            });
+            if let Err(changed_tag) = self.touch_tag.try_set(prev_tag, new_tag) {
+                prev_tag = changed_tag;
+                continue;
+            }
+            self.beendet_hooks_ausfuehren();
             if decoded.is_ready {
-                self.whip(thread_pool)?;
+                // sklave is resting: wake it up so sklavenwelt passes through the shutdown path
+                self.peitschen()?;
             }
+            break;
+        }
 
-            self.orders.push(order);
-            return Ok(());
+        beenden_rx.recv()
+            .map_err(|mpsc::RecvError| self.sklavenwelt_verloren())
+    }
+
+    fn sklavenwelt_verloren(&self) -> Error {
+        self.terminate(TerminationReason::SklavenweltDropped, false);
+        let reason = TouchTag::decompose(self.touch_tag.load()).terminated
+            .unwrap_or(TerminationReason::SklavenweltDropped);
+        self.terminated_error(reason)
+    }
+
+    fn terminated_error(&self, reason: TerminationReason) -> Error {
+        match (reason, self.recorded_panic_message()) {
+            (TerminationReason::Panicked, Some(message)) =>
+                Error::Panicked(message),
+            _ =>
+                Error::Terminated(reason),
         }
     }
 
+    fn terminate(&self, reason: TerminationReason, overwrite: bool) {
+        let mut prev_tag = self.touch_tag.load();
+        loop {
+            let decoded = TouchTag::decompose(prev_tag);
+            if decoded.terminated.is_some() && !overwrite {
+                break;
+            }
+            let new_tag = TouchTag::compose(TouchTagDecoded {
+                terminated: Some(reason),
+                ..decoded
+            });
+            if let Err(changed_tag) = self.touch_tag.try_set(prev_tag, new_tag) {
+                prev_tag = changed_tag;
+                continue;
+            }
+            self.beendet_hooks_ausfuehren();
+            break;
+        }
+    }
+
+    fn panic_isolation<F, R>(&self, action: F) -> Result<R, Error> where F: FnOnce() -> R {
+        panic::catch_unwind(AssertUnwindSafe(action))
+            .map_err(|panic_payload| {
+                let message = panic_message(&*panic_payload);
+                if let Ok(mut panic_message) = self.panic_message.lock() {
+                    panic_message.get_or_insert_with(|| message.clone());
+                }
+                Error::Panicked(message)
+            })
+    }
+
+    fn kennung(&self) -> Kennung {
+        Kennung::new(self.actor_id, &self.name)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn job_span(&self) -> tracing::Span {
+        tracing::debug_span!("sklave_job", actor_id = ?self.actor_id, name = self.name.get().map(String::as_str))
+    }
+
+    fn recorded_panic_message(&self) -> Option<String> {
+        self.panic_message.lock().ok()?.clone()
+    }
+
+    fn shutdown(&self) {
+        let mut prev_tag = self.touch_tag.load();
+        loop {
+            let decoded = TouchTag::decompose(prev_tag);
+            if decoded.terminated.is_some() || decoded.is_shutdown {
+                break;
+            }
+            let new_tag = TouchTag::compose(TouchTagDecoded {
+                is_shutdown: true,
+                ..decoded
+            });
+            if let Err(changed_tag) = self.touch_tag.try_set(prev_tag, new_tag) {
+                prev_tag = changed_tag;
+                continue;
+            }
+            self.beendet_hooks_ausfuehren();
+            break;
+        }
+    }
+
+    fn beendet_hook_installieren(&self, hook: BeendetHook) {
+        if let Ok(mut beendet_hooks) = self.beendet_hooks.lock() {
+            beendet_hooks.push(hook);
+        }
+        let decoded = TouchTag::decompose(self.touch_tag.load());
+        if decoded.is_shutdown || decoded.terminated.is_some() {
+            // raced with termination which might have run the hooks already
+            self.beendet_hooks_ausfuehren();
+        }
+    }
+
+    fn beendet_hooks_ausfuehren(&self) {
+        let beendet_hooks = match self.beendet_hooks.lock() {
+            Ok(mut beendet_hooks) =>
+                mem::take(&mut *beendet_hooks),
+            Err(..) =>
+                return,
+        };
+        for hook in beendet_hooks {
+            hook();
+        }
+    }
+
+    fn drain_dead_letters(&self) {
+        while let Some(Auftrag { order, .. }) = self.orders.pop() {
+            dead_letter(&self.dead_letters, || self.kennung(), order);
+        }
+    }
+
+    fn arbeiter_thread_verlassen(&self) {
+        self.arbeiter_thread
+            .compare_exchange(thread_nummer(), 0, atomic::Ordering::Relaxed, atomic::Ordering::Relaxed)
+            .ok();
+    }
+
+    fn drop_beenden_tx(&self) {
+        if let Ok(mut locked_beenden_tx) = self.beenden_tx.lock() {
+            locked_beenden_tx.take();
+        }
+    }
+
+    fn peitschen(self: &Arc<Self>) -> Result<(), Error> {
+        let Some(peitsche) = self.peitsche.get() else {
+            return Err(Error::SklavenweltMissing);
+        };
+        peitsche(SklaveJob::new(self.clone()))
+            .map_err(|error| {
+                self.terminate(TerminationReason::ThreadPoolSpawn, true);
+                Error::Edeltraud(error)
+            })?;
+        #[cfg(feature = "metrics")]
+        self.metrics.whip();
+        Ok(())
+    }
+
     fn whip<J>(self: &Arc<Self>, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error> where J: From<SklaveJob<W, B>> {
         let sklave_job = SklaveJob::new(self.clone());
         edeltraud::job(thread_pool, sklave_job)
-            .map_err(Error::Edeltraud)
+            .map_err(|error| {
+                // sklave job has been dropped by thread pool, record the actual reason
+                self.terminate(TerminationReason::ThreadPoolSpawn, true);
+                Error::Edeltraud(error)
+            })?;
+        #[cfg(feature = "metrics")]
+        self.metrics.whip();
+        Ok(())
     }
 }
 
 impl<W, B> Meister<W, B> {
-    pub fn befehl<J>(&self, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error> where J: From<SklaveJob<W, B>> {
-        self.inner.befehl(order, thread_pool)
+    pub fn befehl<J>(&self, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), SendError<B>> where J: From<SklaveJob<W, B>> {
+        let mut maybe_order = Some(order);
+        self.inner.befehl_mit(|| maybe_order.take().unwrap(), thread_pool)
+            .map_err(|error| SendError { order: maybe_order.take().unwrap(), error, kennung: self.kennung(), })
+    }
+
+    pub fn try_befehl<J>(&self, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), SendError<B>> where J: From<SklaveJob<W, B>> {
+        let mut maybe_order = Some(order);
+        self.inner.try_befehl_mit(|| maybe_order.take().unwrap(), thread_pool)
+            .map_err(|error| SendError { order: maybe_order.take().unwrap(), error, kennung: self.kennung(), })
+    }
+
+    pub fn befehle<J, I>(&self, orders: I, thread_pool: &edeltraud::Handle<J>) -> Result<(), SendError<Vec<B>>>
+    where J: From<SklaveJob<W, B>>,
+          I: IntoIterator<Item = B>,
+    {
+        self.inner.befehle(orders, thread_pool)
+    }
+
+    pub fn beenden(&self) -> Result<W, Error> {
+        self.inner.beenden()
+    }
+
+    pub fn actor_id(&self) -> ActorId {
+        self.inner.actor_id
+    }
+
+    pub fn kennung(&self) -> Kennung {
+        self.inner.kennung()
+    }
+
+    pub fn status(&self) -> Status {
+        let decoded = TouchTag::decompose(self.inner.touch_tag.load());
+        Status {
+            orders_count: decoded.orders_count,
+            is_resting: decoded.is_ready,
+            is_shutdown: decoded.is_shutdown,
+            terminated: decoded.terminated,
+        }
+    }
+
+    pub fn downgrade(&self) -> SchwachMeister<W, B> {
+        SchwachMeister {
+            maybe_inner: Arc::downgrade(&self.inner),
+            actor_id: self.inner.actor_id,
+            name: self.inner.name.clone(),
+        }
+    }
+}
+
+impl<W, B> SchwachMeister<W, B> {
+    pub fn upgrade(&self) -> Option<Meister<W, B>> {
+        self.maybe_inner.upgrade()
+            .map(|inner| Meister { inner, })
+    }
+
+    pub fn befehl<J>(&self, order: B, thread_pool: &edeltraud::Handle<J>) -> Result<(), SendError<B>> where J: From<SklaveJob<W, B>> {
+        let mut maybe_order = Some(order);
+        self.befehl_mit(&mut || maybe_order.take().unwrap(), thread_pool)
+            .map_err(|error| SendError { order: maybe_order.take().unwrap(), error, kennung: self.kennung(), })
+    }
+
+    pub fn actor_id(&self) -> ActorId {
+        self.actor_id
+    }
+
+    pub fn kennung(&self) -> Kennung {
+        Kennung::new(self.actor_id, &self.name)
+    }
+
+    fn befehl_mit<J, F>(&self, make_order: F, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error>
+    where J: From<SklaveJob<W, B>>,
+          F: FnOnce() -> B,
+    {
+        let inner = self.maybe_inner.upgrade()
+            .ok_or(Error::Terminated(TerminationReason::SklavenweltDropped))?;
+        inner.befehl_mit(make_order, thread_pool)
+    }
+
+    fn try_befehl_mit<J, F>(&self, make_order: F, thread_pool: &edeltraud::Handle<J>) -> Result<(), Error>
+    where J: From<SklaveJob<W, B>>,
+          F: FnOnce() -> B,
+    {
+        let inner = self.maybe_inner.upgrade()
+            .ok_or(Error::Terminated(TerminationReason::SklavenweltDropped))?;
+        inner.try_befehl_mit(make_order, thread_pool)
     }
 }
 
 impl<W, B> SklaveJob<W, B> {
-    pub fn zu_ihren_diensten(mut self) -> Result<Gehorsam<SklaveJob<W, B>>, Error> {
+    pub fn zu_ihren_diensten(self) -> Result<Gehorsam<SklaveJob<W, B>>, Error> {
+        match self.dienen(false)? {
+            Dienst::Gehorsam(gehorsam) =>
+                Ok(gehorsam),
+            Dienst::Beenden { .. } =>
+                unreachable!("beenden is never reported when not requested"),
+        }
+    }
+
+    pub(crate) fn dienen(mut self, beenden_melden: bool) -> Result<Dienst<SklaveJob<W, B>>, Error> {
+        self.neustart_anwenden();
+        self.inner.arbeiter_thread.store(thread_nummer(), atomic::Ordering::Relaxed);
         let mut prev_tag = self.inner.touch_tag.load();
         loop {
             let decoded = TouchTag::decompose(prev_tag);
             assert!(!decoded.is_ready);
-            if decoded.is_terminated {
-                return Err(Error::Terminated);
+            if let Some(reason) = decoded.terminated {
+                return Err(self.inner.terminated_error(reason));
             }
             if decoded.orders_count == 0 {
                 let sklavenwelt = reach_sklavenwelt(&self.inner)
                     .as_ref()
-                    .ok_or(Error::SklavenweltDropped)?;
+                    .ok_or_else(|| self.inner.sklavenwelt_verloren())?;
                 if !sklavenwelt.taken_orders.is_empty() {
-                    return Ok(Gehorsam::Machen {
+                    #[cfg(feature = "metrics")]
+                    self.batch_starten();
+                    return Ok(Dienst::Gehorsam(Gehorsam::Machen {
                         befehle: SklavenBefehle { sklave_job: self, },
+                    }));
+                }
+
+                if decoded.is_shutdown {
+                    if beenden_melden {
+                        // orders are rejected once shutdown is set: nothing can change until termination
+                        return Ok(Dienst::Beenden { sklave_job: self, });
+                    }
+                    let new_tag = TouchTag::compose(TouchTagDecoded {
+                        terminated: Some(TerminationReason::Shutdown),
+                        ..decoded
                     });
+                    if let Err(changed_tag) = self.inner.touch_tag.try_set(prev_tag, new_tag) {
+                        prev_tag = changed_tag;
+                        continue;
+                    }
+
+                    self.rasten_mark = true;
+                    self.inner.arbeiter_thread_verlassen();
+                    let maybe_sklavenwelt = reach_sklavenwelt_mut(&mut self.inner)
+                        .take();
+                    if let Ok(mut locked_beenden_tx) = self.inner.beenden_tx.lock()
+                        && let Some(beenden_tx) = locked_beenden_tx.take()
+                        && let Some(sklavenwelt) = maybe_sklavenwelt
+                    {
+                        beenden_tx.send(sklavenwelt.sklavenwelt).ok();
+                    }
+                    return Ok(Dienst::Gehorsam(Gehorsam::Rasten));
                 }
 
                 let new_tag = TouchTag::compose(TouchTagDecoded {
@@ -248,9 +900,25 @@ impl<W, B> SklaveJob<W, B> {
                     continue;
                 }
 
+                #[cfg(feature = "metrics")]
+                self.inner.metrics.rasten();
                 self.rasten_mark = true;
-                return Ok(Gehorsam::Rasten);
+                self.inner.arbeiter_thread_verlassen();
+                return Ok(Dienst::Gehorsam(Gehorsam::Rasten));
             } else {
+                if let Some(capacity) = self.inner.capacity {
+                    let sklavenwelt = reach_sklavenwelt(&self.inner)
+                        .as_ref()
+                        .ok_or_else(|| self.inner.sklavenwelt_verloren())?;
+                    if sklavenwelt.taken_orders.len() >= capacity {
+                        // taken orders count against capacity too: process them before draining more
+                        #[cfg(feature = "metrics")]
+                        self.batch_starten();
+                        return Ok(Dienst::Gehorsam(Gehorsam::Machen {
+                            befehle: SklavenBefehle { sklave_job: self, },
+                        }));
+                    }
+                }
                 let new_tag = TouchTag::compose(TouchTagDecoded {
                     orders_count: decoded.orders_count - 1,
                     ..decoded
@@ -261,11 +929,17 @@ impl<W, B> SklaveJob<W, B> {
                 }
                 let backoff = crossbeam::utils::Backoff::new();
                 loop {
-                    if let Some(order) = self.inner.orders.pop() {
-                        let sklavenwelt_mut = reach_sklavenwelt_mut(&mut self.inner)
-                            .as_mut()
-                            .ok_or(Error::SklavenweltDropped)?;
-                        sklavenwelt_mut.taken_orders.push_back(order);
+                    if let Some(auftrag) = self.inner.orders.pop() {
+                        #[cfg(feature = "metrics")]
+                        self.inner.metrics.queue_time(auftrag.enqueued_at.elapsed());
+                        match reach_sklavenwelt_mut(&mut self.inner).as_mut() {
+                            Some(sklavenwelt_mut) =>
+                                sklavenwelt_mut.taken_orders.push_back(auftrag),
+                            None => {
+                                dead_letter(&self.inner.dead_letters, || self.inner.kennung(), auftrag.order);
+                                return Err(self.inner.sklavenwelt_verloren());
+                            },
+                        }
                         break;
                     }
                     backoff.snooze();
@@ -291,11 +965,98 @@ impl<W, B> SklaveJob<W, B> {
         }
     }
 
+    pub fn ausfuehren<F>(mut self, mut befehl_machen: F) -> Result<(), Error> where F: FnMut(&mut W, B) {
+        let inner = self.inner.clone();
+        #[cfg(feature = "tracing")]
+        let _job_span = inner.job_span().entered();
+        loop {
+            match self.zu_ihren_diensten()? {
+                Gehorsam::Rasten =>
+                    return Ok(()),
+                Gehorsam::Machen { mut befehle, } =>
+                    loop {
+                        match befehle.befehl() {
+                            SklavenBefehl::Mehr { befehl, mut mehr_befehle, } => {
+                                let sklavenwelt = &mut **mehr_befehle;
+                                // on panic sklave job is dropped without rasten: actor terminates
+                                inner.panic_isolation(|| befehl_machen(sklavenwelt, befehl))?;
+                                befehle = mehr_befehle;
+                            },
+                            SklavenBefehl::Ende { sklave_job, } => {
+                                self = sklave_job;
+                                break;
+                            },
+                        }
+                    },
+            }
+        }
+    }
+
     pub fn meister(&self) -> Meister<W, B> {
         Meister {
             inner: self.inner.clone(),
         }
     }
+
+    pub fn kennung(&self) -> Kennung {
+        self.inner.kennung()
+    }
+
+    fn mark_started(&mut self) -> bool {
+        let sklavenwelt_mut = reach_sklavenwelt_mut(&mut self.inner)
+            .as_mut()
+            .unwrap();
+        !mem::replace(&mut sklavenwelt_mut.is_started, true)
+    }
+
+    #[cfg(feature = "metrics")]
+    fn batch_starten(&mut self) {
+        let sklavenwelt_mut = reach_sklavenwelt_mut(&mut self.inner)
+            .as_mut()
+            .unwrap();
+        sklavenwelt_mut.batch_started_at.get_or_insert_with(std::time::Instant::now);
+    }
+
+    #[cfg(feature = "tracing")]
+    fn befehl_span_betreten(&mut self, sender_span: &tracing::Span) {
+        let befehl_span = tracing::debug_span!(
+            "befehl",
+            actor_id = ?self.inner.actor_id,
+            name = self.inner.name.get().map(String::as_str),
+        );
+        befehl_span.follows_from(sender_span);
+        befehl_span.with_subscriber(|(id, dispatch)| dispatch.enter(id));
+        self.befehl_span = Some(befehl_span);
+    }
+
+    #[cfg(feature = "tracing")]
+    fn befehl_span_verlassen(&mut self) {
+        if let Some(befehl_span) = self.befehl_span.take() {
+            befehl_span.with_subscriber(|(id, dispatch)| dispatch.exit(id));
+        }
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        let decoded = TouchTag::decompose(self.inner.touch_tag.load());
+        decoded.is_shutdown && decoded.orders_count == 0 && decoded.terminated.is_none()
+    }
+}
+
+fn panic_message(panic_payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic_payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic_payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<non string panic payload>".to_string()
+    }
+}
+
+pub(crate) enum Dienst<S> {
+    Gehorsam(Gehorsam<S>),
+    Beenden {
+        sklave_job: S,
+    },
 }
 
 pub enum Gehorsam<S> {
@@ -324,21 +1085,37 @@ pub enum SklavenBefehl<S, B> {
 
 impl<W, B> SklavenBefehle<SklaveJob<W, B>> {
     pub fn befehl(mut self) -> SklavenBefehl<SklaveJob<W, B>, B> {
+        #[cfg(feature = "tracing")]
+        self.sklave_job.befehl_span_verlassen();
         let sklavenwelt_mut = reach_sklavenwelt_mut(&mut self.sklave_job.inner)
             .as_mut()
             .unwrap();
         match sklavenwelt_mut.taken_orders.pop_front() {
-            Some(befehl) =>
-                SklavenBefehl::Mehr { befehl, mehr_befehle: self, },
-            None =>
+            Some(auftrag) => {
+                #[cfg(feature = "metrics")]
+                self.sklave_job.inner.metrics.order_processed();
+                #[cfg(feature = "tracing")]
+                self.sklave_job.befehl_span_betreten(&auftrag.sender_span);
+                SklavenBefehl::Mehr { befehl: auftrag.order, mehr_befehle: self, }
+            },
+            None => {
+                #[cfg(feature = "metrics")]
+                if let Some(batch_started_at) = sklavenwelt_mut.batch_started_at.take() {
+                    self.sklave_job.inner.metrics.batch_time(batch_started_at.elapsed());
+                }
                 SklavenBefehl::Ende {
                     sklave_job: self.sklave_job,
-                },
+                }
+            },
         }
     }
 
     pub fn stoppen(self) -> SklaveJob<W, B> {
-        self.sklave_job
+        #[allow(unused_mut)]
+        let mut sklave_job = self.sklave_job;
+        #[cfg(feature = "tracing")]
+        sklave_job.befehl_span_verlassen();
+        sklave_job
     }
 }
 
@@ -378,27 +1155,86 @@ impl<S> DerefMut for SklavenBefehle<S> {
 
 impl<W, B> Drop for SklaveJob<W, B> {
     fn drop(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.befehl_span_verlassen();
         if !self.rasten_mark {
-            let mut prev_tag = self.inner.touch_tag.load();
-            loop {
-                let decoded = TouchTag::decompose(prev_tag);
-                if decoded.is_terminated {
-                    break;
-                }
-                let new_tag = TouchTag::compose(TouchTagDecoded {
-                    is_terminated: true,
-                    ..decoded
-                });
-                if let Err(changed_tag) = self.inner.touch_tag.try_set(prev_tag, new_tag) {
-                    prev_tag = changed_tag;
-                    continue;
-                }
-                break;
-            }
+            // job still owns the sklave here, so the marker can not belong to anyone else
+            self.inner.arbeiter_thread.store(0, atomic::Ordering::Relaxed);
+        }
+        if !self.rasten_mark && !self.neustarten() {
+            let reason = if std::thread::panicking() || self.inner.recorded_panic_message().is_some() {
+                TerminationReason::Panicked
+            } else {
+                TerminationReason::JobDropped
+            };
+            self.inner.terminate(reason, false);
 
             // drop sklavenwelt
-            let _sklavenwelt =
+            let maybe_sklavenwelt =
                 reach_sklavenwelt_mut(&mut self.inner).take();
+            self.inner.drop_beenden_tx();
+
+            if let Some(sklavenwelt) = maybe_sklavenwelt {
+                for Auftrag { order, .. } in sklavenwelt.taken_orders {
+                    dead_letter(&self.inner.dead_letters, || self.inner.kennung(), order);
+                }
+            }
+            self.inner.drain_dead_letters();
         }
     }
 }
+
+impl<B> fmt::Debug for SendError<B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SendError")
+            .field("error", &self.error)
+            .field("kennung", &self.kennung)
+            .field("<hidden>", &"..")
+            .finish()
+    }
+}
+
+impl<B> fmt::Display for SendError<B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "failed to send order to {}: {:?}", self.kennung, self.error)
+    }
+}
+
+impl<W, B> fmt::Debug for Freie<W, B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Freie")
+            .field("kennung", &self.inner.kennung())
+            .finish()
+    }
+}
+
+impl<W, B> fmt::Debug for Meister<W, B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Meister")
+            .field("kennung", &self.inner.kennung())
+            .finish()
+    }
+}
+
+impl<W, B> fmt::Debug for SchwachMeister<W, B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SchwachMeister")
+            .field("kennung", &self.kennung())
+            .finish()
+    }
+}
+
+impl<W, B> fmt::Debug for SklaveJob<W, B> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SklaveJob")
+            .field("kennung", &self.inner.kennung())
+            .field("rasten_mark", &self.rasten_mark)
+            .finish()
+    }
+}
+
+impl<W, B> Drop for Inner<W, B> {
+    fn drop(&mut self) {
+        self.drain_dead_letters();
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    fmt::{
+        self,
+        Write,
+    },
+    time::{
+        Duration,
+    },
+    sync::{
+        atomic,
+        Arc,
+        Weak,
+        Mutex,
+        OnceLock,
+    },
+};
+
+use crate::{
+    ActorId,
+    Freie,
+};
+
+const BUCKETS: [Duration; 8] = [
+    Duration::from_micros(1),
+    Duration::from_micros(10),
+    Duration::from_micros(100),
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+    Duration::from_secs(10),
+];
+
+const COUNTERS: [&str; 4] = [
+    "arbeitssklave_orders_enqueued_total",
+    "arbeitssklave_orders_processed_total",
+    "arbeitssklave_whips_total",
+    "arbeitssklave_rasten_total",
+];
+
+const HISTOGRAMS: [&str; 2] = [
+    "arbeitssklave_queue_time_seconds",
+    "arbeitssklave_batch_time_seconds",
+];
+
+#[derive(Clone, Default)]
+pub struct Registry {
+    inner: Arc<Mutex<Vec<Weak<Metrics>>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ActorMetrics {
+    pub actor_id: ActorId,
+    pub name: Option<String>,
+    pub orders_enqueued: u64,
+    pub orders_processed: u64,
+    pub whips: u64,
+    pub rasten: u64,
+    pub queue_time: Histogram,
+    pub batch_time: Histogram,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Histogram {
+    pub buckets: Vec<(Duration, u64)>,
+    pub count: u64,
+    pub sum: Duration,
+}
+
+pub(crate) struct Metrics {
+    actor_id: ActorId,
+    name: Arc<OnceLock<String>>,
+    orders_enqueued: atomic::AtomicU64,
+    orders_processed: atomic::AtomicU64,
+    whips: atomic::AtomicU64,
+    rasten: atomic::AtomicU64,
+    queue_time: AtomicHistogram,
+    batch_time: AtomicHistogram,
+}
+
+struct AtomicHistogram {
+    buckets: [atomic::AtomicU64; BUCKETS.len()],
+    count: atomic::AtomicU64,
+    sum_nanos: atomic::AtomicU64,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn snapshot(&self) -> Vec<ActorMetrics> {
+        let Ok(mut sklaven) = self.inner.lock() else {
+            return Vec::new();
+        };
+        sklaven.retain(|metrics| metrics.strong_count() > 0);
+        sklaven.iter()
+            .filter_map(Weak::upgrade)
+            .map(|metrics| metrics.snapshot())
+            .collect()
+    }
+
+    pub fn prometheus(&self) -> String {
+        let mut output = String::new();
+        self.write_prometheus(&mut output).ok();
+        output
+    }
+
+    fn write_prometheus(&self, output: &mut String) -> fmt::Result {
+        let snapshot = self.snapshot();
+
+        for (index, name) in COUNTERS.iter().enumerate() {
+            writeln!(output, "# TYPE {name} counter")?;
+            for actor_metrics in &snapshot {
+                writeln!(output, "{name}{{{}}} {}", actor_metrics.labels(), actor_metrics.counters()[index])?;
+            }
+        }
+
+        for (index, name) in HISTOGRAMS.iter().enumerate() {
+            writeln!(output, "# TYPE {name} histogram")?;
+            for actor_metrics in &snapshot {
+                let labels = actor_metrics.labels();
+                let histogram = actor_metrics.histograms()[index];
+                let mut cumulative = 0;
+                for &(le, count) in &histogram.buckets {
+                    cumulative += count;
+                    writeln!(output, "{name}_bucket{{{labels},le=\"{}\"}} {cumulative}", le.as_secs_f64())?;
+                }
+                writeln!(output, "{name}_bucket{{{labels},le=\"+Inf\"}} {}", histogram.count)?;
+                writeln!(output, "{name}_sum{{{labels}}} {}", histogram.sum.as_secs_f64())?;
+                writeln!(output, "{name}_count{{{labels}}} {}", histogram.count)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ActorMetrics {
+    fn labels(&self) -> String {
+        match &self.name {
+            Some(name) => {
+                let name = name
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n");
+                format!("actor_id=\"{}\",name=\"{name}\"", self.actor_id.id)
+            },
+            None =>
+                format!("actor_id=\"{}\"", self.actor_id.id),
+        }
+    }
+
+    fn counters(&self) -> [u64; COUNTERS.len()] {
+        [self.orders_enqueued, self.orders_processed, self.whips, self.rasten]
+    }
+
+    fn histograms(&self) -> [&Histogram; HISTOGRAMS.len()] {
+        [&self.queue_time, &self.batch_time]
+    }
+}
+
+impl<W, B> Freie<W, B> {
+    pub fn with_metrics(self, registry: &Registry) -> Self {
+        if let Ok(mut sklaven) = registry.inner.lock() {
+            sklaven.push(Arc::downgrade(&self.inner.metrics));
+        }
+        self
+    }
+}
+
+impl Metrics {
+    pub(crate) fn new(actor_id: ActorId, name: Arc<OnceLock<String>>) -> Metrics {
+        Metrics {
+            actor_id,
+            name,
+            orders_enqueued: atomic::AtomicU64::new(0),
+            orders_processed: atomic::AtomicU64::new(0),
+            whips: atomic::AtomicU64::new(0),
+            rasten: atomic::AtomicU64::new(0),
+            queue_time: AtomicHistogram::new(),
+            batch_time: AtomicHistogram::new(),
+        }
+    }
+
+    pub(crate) fn orders_enqueued(&self, count: usize) {
+        self.orders_enqueued.fetch_add(count as u64, atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn order_processed(&self) {
+        self.orders_processed.fetch_add(1, atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn whip(&self) {
+        self.whips.fetch_add(1, atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn rasten(&self) {
+        self.rasten.fetch_add(1, atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn queue_time(&self, elapsed: Duration) {
+        self.queue_time.observe(elapsed);
+    }
+
+    pub(crate) fn batch_time(&self, elapsed: Duration) {
+        self.batch_time.observe(elapsed);
+    }
+
+    fn snapshot(&self) -> ActorMetrics {
+        ActorMetrics {
+            actor_id: self.actor_id,
+            name: self.name.get().cloned(),
+            orders_enqueued: self.orders_enqueued.load(atomic::Ordering::Relaxed),
+            orders_processed: self.orders_processed.load(atomic::Ordering::Relaxed),
+            whips: self.whips.load(atomic::Ordering::Relaxed),
+            rasten: self.rasten.load(atomic::Ordering::Relaxed),
+            queue_time: self.queue_time.snapshot(),
+            batch_time: self.batch_time.snapshot(),
+        }
+    }
+}
+
+impl AtomicHistogram {
+    fn new() -> AtomicHistogram {
+        AtomicHistogram {
+            buckets: Default::default(),
+            count: atomic::AtomicU64::new(0),
+            sum_nanos: atomic::AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, elapsed: Duration) {
+        if let Some(index) = BUCKETS.iter().position(|le| elapsed <= *le) {
+            self.buckets[index].fetch_add(1, atomic::Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, atomic::Ordering::Relaxed);
+        self.sum_nanos.fetch_add(elapsed.as_nanos() as u64, atomic::Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> Histogram {
+        Histogram {
+            buckets: BUCKETS.iter()
+                .zip(self.buckets.iter())
+                .map(|(le, count)| (*le, count.load(atomic::Ordering::Relaxed)))
+                .collect(),
+            count: self.count.load(atomic::Ordering::Relaxed),
+            sum: Duration::from_nanos(self.sum_nanos.load(atomic::Ordering::Relaxed)),
+        }
+    }
+}
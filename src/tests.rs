@@ -4,3 +4,21 @@ mod even_odd_recursive;
 mod ewig_stress;
 mod befehle_stress;
 mod drop_bomb;
+mod bounded;
+mod beenden;
+mod panicked;
+mod dead_letters;
+mod arbeit;
+mod aufseher;
+mod schwach_meister;
+mod status;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod kennung;
+mod verzeichnis;
+mod zeitgeber;
+mod anfrage;
+mod zusage;
+mod stream_credits;
+mod stream_fehler;
+mod rundfunk;
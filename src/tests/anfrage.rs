@@ -0,0 +1,70 @@
+use std::{
+    time::{
+        Duration,
+    },
+};
+
+use crate::{
+    komm::{
+        self,
+        Echo,
+    },
+    Freie,
+    SklaveJob,
+};
+
+struct Order {
+    value: usize,
+    antwort: komm::Antwort<usize>,
+}
+
+struct Welt {
+    held: Vec<komm::Antwort<usize>>,
+}
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, Order { value, antwort, }| match value {
+                0 =>
+                    drop(antwort),
+                1 =>
+                    welt.held.push(antwort),
+                value =>
+                    antwort.commit_echo(value * 2).unwrap(),
+            })
+            .unwrap();
+    }
+}
+
+#[test]
+fn reply_cancel_timeout() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let meister = freie.versklaven(Welt { held: Vec::new(), }, &thread_pool).unwrap();
+
+    let timeout = Duration::from_secs(5);
+    let reply = sendegeraet.anfrage_blocking(|antwort| Order { value: 17, antwort, }, timeout);
+    assert!(matches!(reply, Ok(34)));
+    let reply = sendegeraet.anfrage_blocking(|antwort| Order { value: 0, antwort, }, timeout);
+    assert!(matches!(reply, Err(komm::AnfrageError::Cancelled)));
+    let reply = sendegeraet.anfrage_blocking(|antwort| Order { value: 1, antwort, }, Duration::from_millis(50));
+    assert!(matches!(reply, Err(komm::AnfrageError::Timeout)));
+
+    drop(meister);
+    let reply = sendegeraet.anfrage_blocking(|antwort| Order { value: 2, antwort, }, timeout);
+    assert!(matches!(reply, Err(komm::AnfrageError::Send(..))));
+}
@@ -0,0 +1,140 @@
+use std::{
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    arbeit::{
+        Arbeitssklave,
+        Context,
+    },
+    Freie,
+    SklaveJob,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+enum Event {
+    Started,
+    Value(usize),
+    Stopped { sum: usize, },
+}
+
+enum Order {
+    Add(usize),
+    Stop,
+}
+
+struct Welt {
+    sum: usize,
+    events_tx: mpsc::Sender<Event>,
+}
+
+impl<J> Arbeitssklave<Order, J> for Welt {
+    fn befehl(&mut self, order: Order, context: &mut Context<'_, Self, Order, J>) {
+        match order {
+            Order::Add(value) => {
+                self.sum += value;
+                self.events_tx.send(Event::Value(value)).unwrap();
+            },
+            Order::Stop =>
+                context.beenden(),
+        }
+    }
+
+    fn starten(&mut self, _context: &mut Context<'_, Self, Order, J>) {
+        self.events_tx.send(Event::Started).unwrap();
+    }
+
+    fn beenden(&mut self, _context: &mut Context<'_, Self, Order, J>) {
+        self.events_tx.send(Event::Stopped { sum: self.sum, }).unwrap();
+    }
+}
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job.arbeiten(&self.0.handle).unwrap();
+    }
+}
+
+#[test]
+fn hooks() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (events_tx, events_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { sum: 0, events_tx, }, &thread_pool)
+        .unwrap();
+
+    meister.befehle([Order::Add(1), Order::Add(2), Order::Stop, Order::Add(3)], &thread_pool).unwrap();
+
+    let events: Vec<_> = events_rx.iter().collect();
+    assert_eq!(
+        events,
+        vec![Event::Started, Event::Value(1), Event::Value(2), Event::Value(3), Event::Stopped { sum: 6, }],
+    );
+
+    assert!(matches!(
+        meister.befehl(Order::Add(4), &thread_pool),
+        Err(crate::SendError {
+            error: crate::Error::Terminated(crate::TerminationReason::Shutdown),
+            ..
+        }),
+    ));
+}
+
+#[test]
+fn beenden_resting() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (events_tx, events_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { sum: 0, events_tx, }, &thread_pool)
+        .unwrap();
+
+    meister.befehl(Order::Add(5), &thread_pool).unwrap();
+    assert_eq!(events_rx.recv(), Ok(Event::Started));
+    assert_eq!(events_rx.recv(), Ok(Event::Value(5)));
+    while !meister.status().is_resting {
+        std::thread::yield_now();
+    }
+
+    let welt = meister.beenden().unwrap();
+    assert_eq!(welt.sum, 5);
+    assert_eq!(events_rx.try_recv(), Ok(Event::Stopped { sum: 5, }));
+}
+
+#[test]
+fn beenden_racing_batch_end() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    for value in 0 .. 256 {
+        let (events_tx, events_rx) = mpsc::channel();
+        let meister = Freie::new()
+            .versklaven(Welt { sum: 0, events_tx, }, &thread_pool)
+            .unwrap();
+
+        meister.befehl(Order::Add(value), &thread_pool).unwrap();
+        let welt = meister.beenden().unwrap();
+        assert_eq!(welt.sum, value);
+        let events: Vec<_> = events_rx.try_iter().collect();
+        assert_eq!(events.last(), Some(&Event::Stopped { sum: value, }));
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    time::{
+        Duration,
+    },
+    sync::{
+        mpsc,
+        atomic,
+        Arc,
+        Mutex,
+    },
+};
+
+use crate::{
+    arbeit::{
+        Arbeitssklave,
+        Context,
+    },
+    aufseher::{
+        Params,
+        Aufseher,
+        RestartStrategy,
+    },
+    Freie,
+    Meister,
+    SklaveJob,
+};
+
+enum Order {
+    Add(usize),
+    Boom,
+    Get(mpsc::Sender<(usize, usize)>),
+}
+
+struct Welt {
+    generation: usize,
+    sum: usize,
+}
+
+impl<J> Arbeitssklave<Order, J> for Welt {
+    fn befehl(&mut self, order: Order, _context: &mut Context<'_, Self, Order, J>) {
+        match order {
+            Order::Add(value) =>
+                self.sum += value,
+            Order::Boom =>
+                panic!("boom"),
+            Order::Get(reply_tx) =>
+                reply_tx.send((self.generation, self.sum)).unwrap(),
+        }
+    }
+}
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job.arbeiten(&self.0.handle).ok();
+    }
+}
+
+fn welt_factory() -> impl Fn() -> Welt + Send + Sync + 'static {
+    let generations = Arc::new(atomic::AtomicUsize::new(0));
+    move || Welt {
+        generation: generations.fetch_add(1, atomic::Ordering::SeqCst),
+        sum: 0,
+    }
+}
+
+fn get<J>(meister: &Meister<Welt, Order>, thread_pool: &edeltraud::Handle<J>) -> (usize, usize) where J: From<SklaveJob<Welt, Order>> {
+    let (reply_tx, reply_rx) = mpsc::channel();
+    meister.befehl(Order::Get(reply_tx), thread_pool).unwrap();
+    reply_rx.recv().unwrap()
+}
+
+#[test]
+fn one_for_one() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let aufseher = Aufseher::new(Params {
+        restart_strategy: RestartStrategy::OneForOne,
+        max_restarts: 1,
+        restarts_window: Duration::from_secs(60),
+    });
+    let meister = aufseher.versklaven(Freie::new(), welt_factory(), &thread_pool).unwrap();
+    let meister_other = aufseher.versklaven(Freie::new(), welt_factory(), &thread_pool).unwrap();
+
+    meister.befehle([Order::Add(1), Order::Add(2)], &thread_pool).unwrap();
+    meister_other.befehl(Order::Add(5), &thread_pool).unwrap();
+    assert_eq!(get(&meister, &thread_pool), (0, 3));
+
+    meister.befehle([Order::Boom, Order::Add(4)], &thread_pool).unwrap();
+    assert_eq!(get(&meister, &thread_pool), (1, 4));
+    assert_eq!(get(&meister_other, &thread_pool), (0, 5));
+
+    meister.befehl(Order::Boom, &thread_pool).unwrap();
+    let (reply_tx, _reply_rx) = mpsc::channel();
+    let backoff = crossbeam::utils::Backoff::new();
+    loop {
+        match meister.befehl(Order::Get(reply_tx.clone()), &thread_pool) {
+            Ok(()) =>
+                backoff.snooze(),
+            Err(crate::SendError { error: crate::Error::Panicked(message), .. }) => {
+                assert_eq!(message, "boom");
+                break;
+            },
+            Err(other) =>
+                panic!("unexpected error: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn one_for_all() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let aufseher = Aufseher::new(Params {
+        restart_strategy: RestartStrategy::OneForAll,
+        ..Params::default()
+    });
+    let meister_a = aufseher.versklaven(Freie::new(), welt_factory(), &thread_pool).unwrap();
+    let meister_b = aufseher.versklaven(Freie::new(), welt_factory(), &thread_pool).unwrap();
+
+    meister_a.befehl(Order::Add(1), &thread_pool).unwrap();
+    meister_b.befehl(Order::Add(2), &thread_pool).unwrap();
+    assert_eq!(get(&meister_a, &thread_pool), (0, 1));
+    assert_eq!(get(&meister_b, &thread_pool), (0, 2));
+
+    meister_a.befehl(Order::Boom, &thread_pool).unwrap();
+    assert_eq!(get(&meister_a, &thread_pool), (1, 0));
+    assert_eq!(get(&meister_b, &thread_pool), (1, 0));
+}
+
+#[test]
+fn one_for_all_resting_sibling() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let aufseher = Aufseher::new(Params {
+        restart_strategy: RestartStrategy::OneForAll,
+        ..Params::default()
+    });
+    let meister_a = aufseher.versklaven(Freie::new(), welt_factory(), &thread_pool).unwrap();
+    let (generations_tx, generations_rx) = mpsc::channel();
+    let generations_tx = Mutex::new(generations_tx);
+    let factory_b = welt_factory();
+    let factory_b = move || {
+        let welt = factory_b();
+        generations_tx.lock().unwrap().send(welt.generation).unwrap();
+        welt
+    };
+    let meister_b = aufseher.versklaven(Freie::new(), factory_b, &thread_pool).unwrap();
+    assert_eq!(generations_rx.recv(), Ok(0));
+    assert_eq!(get(&meister_b, &thread_pool), (0, 0));
+    while !meister_b.status().is_resting {
+        std::thread::yield_now();
+    }
+
+    meister_a.befehl(Order::Boom, &thread_pool).unwrap();
+    // resting sibling is restarted without waiting for its next order
+    assert_eq!(generations_rx.recv_timeout(Duration::from_secs(5)), Ok(1));
+    assert_eq!(get(&meister_b, &thread_pool), (1, 0));
+}
+
+#[test]
+fn factory_panic() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let aufseher = Aufseher::new(Params::default());
+    let factory = welt_factory();
+    let factory = move || {
+        let welt = factory();
+        assert_eq!(welt.generation, 0, "factory failed");
+        welt
+    };
+    let meister = aufseher.versklaven(Freie::new(), factory, &thread_pool).unwrap();
+
+    meister.befehl(Order::Boom, &thread_pool).unwrap();
+    let (reply_tx, _reply_rx) = mpsc::channel();
+    let backoff = crossbeam::utils::Backoff::new();
+    loop {
+        match meister.befehl(Order::Get(reply_tx.clone()), &thread_pool) {
+            Ok(()) =>
+                backoff.snooze(),
+            Err(crate::SendError { error: crate::Error::Panicked(message), .. }) => {
+                assert_eq!(message, "boom");
+                break;
+            },
+            Err(other) =>
+                panic!("unexpected error: {other:?}"),
+        }
+    }
+}
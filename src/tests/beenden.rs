@@ -0,0 +1,198 @@
+use std::{
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    Error,
+    Freie,
+    Meister,
+    Gehorsam,
+    SklaveJob,
+    SklavenBefehl,
+    TerminationReason,
+};
+
+struct Welt {
+    sum: usize,
+    orders_count: usize,
+}
+
+struct Order(usize);
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        let mut sklave_job = self.0.job;
+        loop {
+            match sklave_job.zu_ihren_diensten().unwrap() {
+                Gehorsam::Rasten =>
+                    break,
+                Gehorsam::Machen { mut befehle, } =>
+                    loop {
+                        match befehle.befehl() {
+                            SklavenBefehl::Mehr { befehl: Order(value), mehr_befehle, } => {
+                                befehle = mehr_befehle;
+                                befehle.sum += value;
+                                befehle.orders_count += 1;
+                            },
+                            SklavenBefehl::Ende { sklave_job: next_sklave_job, } => {
+                                sklave_job = next_sklave_job;
+                                break;
+                            },
+                        }
+                    },
+            }
+        }
+    }
+}
+
+#[test]
+fn drain_queue() {
+    const ORDERS_COUNT: usize = 65536;
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(Welt { sum: 0, orders_count: 0, }, &thread_pool)
+        .unwrap();
+    for value in 0 .. ORDERS_COUNT {
+        meister.befehl(Order(value), &thread_pool).unwrap();
+    }
+
+    let welt = meister.beenden().unwrap();
+    assert_eq!(welt.orders_count, ORDERS_COUNT);
+    assert_eq!(welt.sum, (0 .. ORDERS_COUNT).sum());
+
+    assert!(matches!(
+        meister.befehl(Order(0), &thread_pool),
+        Err(crate::SendError {
+            error: Error::Terminated(TerminationReason::Shutdown),
+            ..
+        }),
+    ));
+    assert!(matches!(meister.beenden(), Err(Error::Terminated(TerminationReason::Shutdown))));
+}
+
+#[test]
+fn resting() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(Welt { sum: 0, orders_count: 0, }, &thread_pool)
+        .unwrap();
+    let welt = meister.beenden().unwrap();
+    assert_eq!(welt.orders_count, 0);
+}
+
+#[test]
+fn before_versklaven() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let freie = Freie::new();
+    let meister = freie.meister();
+    assert!(matches!(meister.beenden(), Err(Error::SklavenweltMissing)));
+
+    freie.versklaven(Welt { sum: 0, orders_count: 0, }, &thread_pool).unwrap();
+    meister.befehl(Order(7), &thread_pool).unwrap();
+    let welt = meister.beenden().unwrap();
+    assert_eq!(welt.sum, 7);
+}
+
+#[test]
+fn reentrant() {
+    struct ReentrantWelt {
+        maybe_meister: Option<Meister<ReentrantWelt, ReentrantOrder>>,
+        results_tx: mpsc::Sender<bool>,
+    }
+
+    struct ReentrantOrder(Meister<ReentrantWelt, ReentrantOrder>);
+
+    struct ReentrantJobUnit<J>(edeltraud::JobUnit<J, SklaveJob<ReentrantWelt, ReentrantOrder>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<ReentrantWelt, ReentrantOrder>>> for ReentrantJobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<ReentrantWelt, ReentrantOrder>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for ReentrantJobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, ReentrantOrder(meister)| {
+                    let result = meister.beenden();
+                    welt.results_tx.send(matches!(result, Err(Error::BeendenReentrant))).unwrap();
+                    welt.maybe_meister = Some(meister);
+                })
+                .unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, ReentrantJobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (results_tx, results_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(ReentrantWelt { maybe_meister: None, results_tx, }, &thread_pool)
+        .unwrap();
+    meister.befehl(ReentrantOrder(meister.clone()), &thread_pool).unwrap();
+    assert_eq!(results_rx.recv(), Ok(true));
+
+    let welt = meister.beenden().unwrap();
+    assert!(welt.maybe_meister.is_some());
+}
+
+#[test]
+fn job_dropped() {
+    struct DropWelt;
+
+    struct DropOrder;
+
+    struct DropJobUnit<J>(edeltraud::JobUnit<J, SklaveJob<DropWelt, DropOrder>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<DropWelt, DropOrder>>> for DropJobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<DropWelt, DropOrder>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for DropJobUnit<J> {
+        fn run(self) {
+            if let Gehorsam::Machen { befehle, } = self.0.job.zu_ihren_diensten().unwrap() {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                // sklave job is dropped here together with sklavenwelt
+                drop(befehle);
+            }
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, DropJobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(DropWelt, &thread_pool)
+        .unwrap();
+    meister.befehl(DropOrder, &thread_pool).unwrap();
+    assert!(matches!(meister.beenden(), Err(Error::Terminated(TerminationReason::JobDropped))));
+}
@@ -165,3 +165,102 @@ fn many_to_one() {
 
     assert_eq!(done_rx.recv(), Ok(JOBS_COUNT * INCS_COUNT));
 }
+
+#[test]
+fn many_to_one_batched() {
+    const BATCH_SIZE: usize = 64;
+    const BATCHES_COUNT: usize = 2048;
+    const JOBS_COUNT: usize = 8;
+
+    enum Job {
+        Feeder(FeederJob),
+        Consumer(SklaveJob<ConsumerWelt, usize>),
+    }
+
+    impl From<SklaveJob<ConsumerWelt, usize>> for Job {
+        fn from(job: SklaveJob<ConsumerWelt, usize>) -> Self {
+            Self::Consumer(job)
+        }
+    }
+
+    struct FeederJob {
+        consumer_meister: Meister<ConsumerWelt, usize>,
+    }
+
+    struct ConsumerWelt {
+        total_orders: usize,
+        local_counter: usize,
+        done_tx: mpsc::Sender<usize>,
+    }
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, Job>);
+
+    impl<J> From<edeltraud::JobUnit<J, Job>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, Job>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> where J: From<SklaveJob<ConsumerWelt, usize>>, {
+        fn run(self) {
+            match self.0.job {
+                Job::Feeder(FeederJob { consumer_meister, }) =>
+                    for _ in 0 .. BATCHES_COUNT {
+                        consumer_meister
+                            .befehle(std::iter::repeat_n(1, BATCH_SIZE), &self.0.handle)
+                            .unwrap();
+                    },
+                Job::Consumer(mut sklave_job) =>
+                    loop {
+                        match sklave_job.zu_ihren_diensten().unwrap() {
+                            Gehorsam::Rasten =>
+                                break,
+                            Gehorsam::Machen { mut befehle, } =>
+                                loop {
+                                    match befehle.befehl() {
+                                        SklavenBefehl::Ende { sklave_job: next_sklave_job, } => {
+                                            sklave_job = next_sklave_job;
+                                            break;
+                                        },
+                                        SklavenBefehl::Mehr { befehl: value, mehr_befehle, } => {
+                                            befehle = mehr_befehle;
+                                            let sklavenwelt = &mut *befehle;
+                                            sklavenwelt.total_orders += 1;
+                                            sklavenwelt.local_counter += value;
+                                            if sklavenwelt.total_orders >= JOBS_COUNT * BATCHES_COUNT * BATCH_SIZE {
+                                                sklavenwelt.done_tx.send(sklavenwelt.local_counter).unwrap();
+                                                return;
+                                            }
+                                        },
+                                    }
+                                },
+                        }
+                    },
+            }
+        }
+    }
+
+    let edeltraud: edeltraud::Edeltraud<Job> = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let consumer_meister = Freie::new()
+        .versklaven(
+            ConsumerWelt {
+                total_orders: 0,
+                local_counter: 0,
+                done_tx,
+            },
+            &thread_pool,
+        )
+        .unwrap();
+
+    for _ in 0 .. JOBS_COUNT {
+        edeltraud::job(&thread_pool, Job::Feeder(FeederJob { consumer_meister: consumer_meister.clone(), }))
+            .unwrap();
+    }
+
+    assert_eq!(done_rx.recv(), Ok(JOBS_COUNT * BATCHES_COUNT * BATCH_SIZE));
+}
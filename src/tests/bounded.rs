@@ -0,0 +1,254 @@
+use std::{
+    thread,
+    time::{
+        Duration,
+    },
+    sync::{
+        atomic,
+        mpsc,
+        Arc,
+    },
+};
+
+use crate::{
+    komm,
+    Freie,
+    Gehorsam,
+    SklaveJob,
+    SklavenBefehl,
+};
+
+#[test]
+fn try_befehl_full() {
+    const CAPACITY: usize = 4;
+    const ORDERS_COUNT: usize = 1024;
+
+    struct Welt {
+        done_tx: mpsc::Sender<usize>,
+    }
+
+    struct Order(usize);
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            let mut sklave_job = self.0.job;
+            loop {
+                match sklave_job.zu_ihren_diensten().unwrap() {
+                    Gehorsam::Rasten =>
+                        break,
+                    Gehorsam::Machen { mut befehle, } =>
+                        loop {
+                            match befehle.befehl() {
+                                SklavenBefehl::Mehr { befehl: Order(value), mehr_befehle, } => {
+                                    befehle = mehr_befehle;
+                                    befehle.done_tx.send(value).unwrap();
+                                },
+                                SklavenBefehl::Ende { sklave_job: next_sklave_job, } => {
+                                    sklave_job = next_sklave_job;
+                                    break;
+                                },
+                            }
+                        },
+                }
+            }
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let freie = Freie::with_capacity(CAPACITY);
+    let meister = freie.meister();
+
+    for value in 0 .. CAPACITY {
+        meister.try_befehl(Order(value), &thread_pool).unwrap();
+    }
+    assert!(matches!(
+        meister.try_befehl(Order(CAPACITY), &thread_pool),
+        Err(crate::SendError { order: Order(CAPACITY), error: crate::Error::Full, .. }),
+    ));
+
+    let _meister = freie.versklaven(Welt { done_tx, }, &thread_pool).unwrap();
+    for value in CAPACITY .. ORDERS_COUNT {
+        meister.befehl(Order(value), &thread_pool).unwrap();
+    }
+
+    let received: Vec<_> = done_rx.iter().take(ORDERS_COUNT).collect();
+    assert_eq!(received, (0 .. ORDERS_COUNT).collect::<Vec<_>>());
+}
+
+#[test]
+fn drain_stays_bounded() {
+    const CAPACITY: usize = 16;
+    const PRODUCERS_COUNT: usize = 8;
+    const ORDERS_COUNT: usize = 256;
+
+    #[derive(Default)]
+    struct Zaehler {
+        alive: atomic::AtomicUsize,
+        max_alive: atomic::AtomicUsize,
+    }
+
+    struct Lebendig {
+        zaehler: Arc<Zaehler>,
+    }
+
+    impl Lebendig {
+        fn new(zaehler: &Arc<Zaehler>) -> Lebendig {
+            let alive = zaehler.alive.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+            zaehler.max_alive.fetch_max(alive, atomic::Ordering::SeqCst);
+            Lebendig { zaehler: zaehler.clone(), }
+        }
+    }
+
+    impl Drop for Lebendig {
+        fn drop(&mut self) {
+            self.zaehler.alive.fetch_sub(1, atomic::Ordering::SeqCst);
+        }
+    }
+
+    enum Order {
+        Umschlag(#[allow(dead_code)] Lebendig),
+        Abbrechen,
+    }
+
+    impl From<komm::Umschlag<Lebendig, ()>> for Order {
+        fn from(umschlag: komm::Umschlag<Lebendig, ()>) -> Order {
+            // slow conversion runs after the order is counted but before it is queued
+            thread::sleep(Duration::from_micros(50));
+            Order::Umschlag(umschlag.inhalt)
+        }
+    }
+
+    impl From<komm::UmschlagAbbrechen<()>> for Order {
+        fn from(_umschlag_abbrechen: komm::UmschlagAbbrechen<()>) -> Order {
+            Order::Abbrechen
+        }
+    }
+
+    struct Welt {
+        done_tx: mpsc::Sender<()>,
+    }
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, order| {
+                    thread::sleep(Duration::from_micros(20));
+                    if let Order::Umschlag(lebendig) = order {
+                        drop(lebendig);
+                        welt.done_tx.send(()).unwrap();
+                    }
+                })
+                .unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let freie = Freie::with_capacity(CAPACITY);
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie.versklaven(Welt { done_tx, }, &thread_pool).unwrap();
+    let zaehler = Arc::new(Zaehler::default());
+
+    let producers: Vec<_> = (0 .. PRODUCERS_COUNT)
+        .map(|_| {
+            let sendegeraet = sendegeraet.clone();
+            let zaehler = zaehler.clone();
+            thread::spawn(move || {
+                for _ in 0 .. ORDERS_COUNT {
+                    let lebendig = Lebendig::new(&zaehler);
+                    sendegeraet.rueckkopplung(()).commit(lebendig).unwrap();
+                }
+            })
+        })
+        .collect();
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    assert_eq!(done_rx.iter().take(PRODUCERS_COUNT * ORDERS_COUNT).count(), PRODUCERS_COUNT * ORDERS_COUNT);
+
+    // queued + taken + one in every producer hand + one being processed
+    let max_alive = zaehler.max_alive.load(atomic::Ordering::SeqCst);
+    assert!(max_alive <= 2 * CAPACITY + PRODUCERS_COUNT + 1, "max alive orders: {max_alive}");
+}
+
+#[test]
+fn befehl_from_own_job_full() {
+    struct Welt {
+        maybe_sendegeraet: Option<komm::Sendegeraet<Order>>,
+        results_tx: mpsc::Sender<Result<(), crate::Error>>,
+    }
+
+    enum Order {
+        Start,
+        FollowUp,
+    }
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, order| {
+                    if let Order::Start = order {
+                        let sendegeraet = welt.maybe_sendegeraet.as_ref().unwrap();
+                        for _ in 0 .. 2 {
+                            let result = sendegeraet.befehl(Order::FollowUp)
+                                .map_err(|send_error| send_error.error);
+                            welt.results_tx.send(result).unwrap();
+                        }
+                    }
+                })
+                .unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .worker_threads(4)
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (results_tx, results_rx) = mpsc::channel();
+    let freie = Freie::with_capacity(1);
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let meister = freie
+        .versklaven(Welt { maybe_sendegeraet: Some(sendegeraet), results_tx, }, &thread_pool)
+        .unwrap();
+    meister.befehl(Order::Start, &thread_pool).unwrap();
+
+    let timeout = Duration::from_secs(5);
+    assert!(matches!(results_rx.recv_timeout(timeout), Ok(Ok(()))));
+    // waiting for space on own thread would never end
+    assert!(matches!(results_rx.recv_timeout(timeout), Ok(Err(crate::Error::Full))));
+}
@@ -0,0 +1,123 @@
+use std::{
+    sync::{
+        mpsc,
+        Mutex,
+    },
+};
+
+use crate::{
+    komm,
+    Freie,
+    Gehorsam,
+    SklaveJob,
+    SklavenBefehl,
+};
+
+struct Stamp;
+
+enum Order {
+    Value(usize),
+    Boom,
+    Abbrechen(komm::UmschlagAbbrechen<Stamp>),
+}
+
+impl From<komm::UmschlagAbbrechen<Stamp>> for Order {
+    fn from(umschlag_abbrechen: komm::UmschlagAbbrechen<Stamp>) -> Order {
+        Order::Abbrechen(umschlag_abbrechen)
+    }
+}
+
+struct Welt;
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        let mut sklave_job = self.0.job;
+        loop {
+            match sklave_job.zu_ihren_diensten().unwrap() {
+                Gehorsam::Rasten =>
+                    break,
+                Gehorsam::Machen { mut befehle, } =>
+                    loop {
+                        match befehle.befehl() {
+                            SklavenBefehl::Mehr { befehl: Order::Value(..) | Order::Abbrechen(..), mehr_befehle, } =>
+                                befehle = mehr_befehle,
+                            SklavenBefehl::Mehr { befehl: Order::Boom, .. } =>
+                                return,
+                            SklavenBefehl::Ende { sklave_job: next_sklave_job, } => {
+                                sklave_job = next_sklave_job;
+                                break;
+                            },
+                        }
+                    },
+            }
+        }
+    }
+}
+
+#[test]
+fn orders_left_in_queue() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (dead_tx, dead_rx) = mpsc::channel();
+    let dead_tx = Mutex::new(dead_tx);
+    let freie = Freie::new()
+        .with_name("dead_letters")
+        .with_dead_letters(move |dead_letter: crate::DeadLetter<Order>| {
+            if let Order::Value(value) = dead_letter.order {
+                dead_tx.lock().unwrap().send((dead_letter.kennung, value)).ok();
+            }
+        });
+    let kennung = freie.kennung();
+    assert_eq!(kennung.name.as_deref(), Some("dead_letters"));
+    let meister = freie.meister();
+    meister.befehl(Order::Value(1), &thread_pool).unwrap();
+    meister.befehl(Order::Boom, &thread_pool).unwrap();
+    meister.befehl(Order::Value(2), &thread_pool).unwrap();
+    meister.befehl(Order::Value(3), &thread_pool).unwrap();
+    freie.versklaven(Welt, &thread_pool).unwrap();
+
+    assert_eq!(dead_rx.recv(), Ok((kennung.clone(), 2)));
+    assert_eq!(dead_rx.recv(), Ok((kennung, 3)));
+}
+
+#[test]
+fn rueckkopplung_abbrechen() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (dead_tx, dead_rx) = mpsc::channel();
+    let dead_tx = Mutex::new(dead_tx);
+    let meister = Freie::new()
+        .with_dead_letters(move |dead_letter: crate::DeadLetter<Order>| {
+            if let Order::Abbrechen(komm::UmschlagAbbrechen { stamp: Stamp, }) = dead_letter.order {
+                dead_tx.lock().unwrap().send(dead_letter.kennung).ok();
+            }
+        })
+        .versklaven(Welt, &thread_pool)
+        .unwrap();
+
+    let sendegeraet =
+        komm::Sendegeraet::starten(
+            &meister,
+            #[allow(clippy::redundant_clone)]
+            thread_pool.clone(),
+        );
+    let rueckkopplung = sendegeraet.rueckkopplung(Stamp);
+    meister.beenden().unwrap();
+    drop(rueckkopplung);
+
+    assert_eq!(dead_rx.recv(), Ok(meister.kennung()));
+}
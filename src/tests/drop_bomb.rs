@@ -136,4 +136,13 @@ fn drop_bomb_with_umschlag_abbrechen() {
         sync_rx.recv_timeout(std::time::Duration::from_millis(100)),
         Ok(RecvOrderBoom),
     ));
+
+    assert!(matches!(
+        bomb_meister.befehl(BombOrderTerminate, &thread_pool),
+        Err(crate::SendError {
+            order: BombOrderTerminate,
+            error: crate::Error::Terminated(crate::TerminationReason::JobDropped),
+            ..
+        }),
+    ));
 }
@@ -385,7 +385,7 @@ mod odd {
 
     pub fn start<E, J>(thread_pool: &edeltraud::Handle<J>) -> Meister<Welt, Order<E>>
     where E: komm::Echo<Outcome> + Send + 'static,
-          J: From<SklaveJob<Welt, Order<E>>>,
+          J: From<SklaveJob<Welt, Order<E>>> + Send + 'static,
     {
         let freie = Freie::new();
         freie.versklaven(Welt, thread_pool).unwrap()
@@ -474,7 +474,7 @@ mod even {
 
     pub fn start<E, J>(thread_pool: &edeltraud::Handle<J>) -> Meister<Welt, Order<E>>
     where E: komm::Echo<Outcome> + Send + 'static,
-          J: From<SklaveJob<Welt, Order<E>>>,
+          J: From<SklaveJob<Welt, Order<E>>> + Send + 'static,
     {
         let freie = Freie::new();
         freie.versklaven(Welt, thread_pool).unwrap()
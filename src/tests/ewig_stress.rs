@@ -65,14 +65,17 @@ fn stress_8() {
 
     let consumer_meister = ewig::Freie::new()
         .versklaven(move |sklave| {
+            let mut feeders_regs = 0;
             let mut feeders_count = 0;
             loop {
                 let orders = sklave.zu_ihren_diensten()?;
                 for order in orders {
                     match order {
-                        ConsumerOrder::Register =>
-                            feeders_count += 1,
-                        ConsumerOrder::Unregister if feeders_count < 2 => {
+                        ConsumerOrder::Register => {
+                            feeders_regs += 1;
+                            feeders_count += 1;
+                        },
+                        ConsumerOrder::Unregister if feeders_count < 2 && feeders_regs >= JOBS_COUNT => {
                             ewig_barrier.wait();
                             return Ok(());
                         },
@@ -0,0 +1,60 @@
+use crate::{
+    komm,
+    Freie,
+    Error,
+    Kennung,
+    SendError,
+    SklaveJob,
+    TerminationReason,
+};
+
+#[test]
+fn named_actor() {
+    struct Welt;
+
+    struct Order;
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job.ausfuehren(|_welt, Order| ()).unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let freie = Freie::new().with_name("worker");
+    let actor_id = freie.actor_id();
+    let kennung = Kennung { actor_id, name: Some("worker".to_string()), };
+    assert_eq!(freie.kennung(), kennung);
+    assert_ne!(Freie::<Welt, Order>::new().actor_id(), actor_id);
+
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let meister = freie.versklaven(Welt, &thread_pool).unwrap();
+    let display = kennung.to_string();
+    assert!(display.starts_with("worker#"));
+    assert!(format!("{meister:?}").contains("\"worker\""));
+    assert!(format!("{:?}", meister.downgrade()).contains("\"worker\""));
+    assert!(format!("{sendegeraet:?}").contains("\"worker\""));
+
+    meister.beenden().unwrap();
+    let send_error = meister.befehl(Order, &thread_pool).unwrap_err();
+    assert_eq!(send_error.kennung, kennung);
+    assert!(matches!(send_error.error, Error::Terminated(TerminationReason::Shutdown)));
+    assert!(send_error.to_string().contains(&display));
+
+    assert!(matches!(
+        sendegeraet.befehl(Order),
+        Err(SendError { kennung: Kennung { name: Some(..), .. }, .. }),
+    ));
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    metrics::{
+        Registry,
+    },
+    Freie,
+    SklaveJob,
+};
+
+#[test]
+fn counters_and_prometheus() {
+    struct Welt {
+        values_tx: mpsc::Sender<usize>,
+    }
+
+    struct Order(usize);
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, Order(value)| welt.values_tx.send(value).unwrap())
+                .unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let registry = Registry::new();
+    let (values_tx, values_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .with_name("metered")
+        .with_metrics(&registry)
+        .versklaven(Welt { values_tx, }, &thread_pool)
+        .unwrap();
+
+    meister.befehle([Order(1), Order(2), Order(3)], &thread_pool).unwrap();
+    assert_eq!(values_rx.iter().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    let backoff = crossbeam::utils::Backoff::new();
+    while !meister.status().is_resting {
+        backoff.snooze();
+    }
+
+    let snapshot = registry.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    let actor_metrics = &snapshot[0];
+    assert_eq!(actor_metrics.actor_id, meister.actor_id());
+    assert_eq!(actor_metrics.name.as_deref(), Some("metered"));
+    assert_eq!(actor_metrics.orders_enqueued, 3);
+    assert_eq!(actor_metrics.orders_processed, 3);
+    assert_eq!(actor_metrics.queue_time.count, 3);
+    assert!(actor_metrics.whips >= 1);
+    assert!(actor_metrics.rasten >= 1);
+    assert!(actor_metrics.batch_time.count >= 1);
+
+    let prometheus = registry.prometheus();
+    assert!(prometheus.contains("# TYPE arbeitssklave_orders_processed_total counter\n"));
+    assert!(prometheus.contains(&format!(
+        "arbeitssklave_queue_time_seconds_count{{actor_id=\"{}\",name=\"metered\"}} 3\n",
+        meister.actor_id().id,
+    )));
+    assert!(prometheus.contains("le=\"+Inf\"} 3\n"));
+
+    drop(meister);
+    let backoff = crossbeam::utils::Backoff::new();
+    while !registry.snapshot().is_empty() {
+        backoff.snooze();
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    Freie,
+    SklaveJob,
+};
+
+#[test]
+fn panic_message() {
+    struct Welt {
+        done_tx: mpsc::Sender<usize>,
+    }
+
+    enum Order {
+        Value(usize),
+        Boom,
+    }
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            let result = self.0.job.ausfuehren(|sklavenwelt, order| {
+                match order {
+                    Order::Value(value) =>
+                        sklavenwelt.done_tx.send(value).unwrap(),
+                    Order::Boom =>
+                        panic!("boom"),
+                }
+            });
+            if let Err(error) = result {
+                log::debug!("sklave job finished with {error:?}");
+            }
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { done_tx, }, &thread_pool)
+        .unwrap();
+
+    meister.befehl(Order::Value(1), &thread_pool).unwrap();
+    assert_eq!(done_rx.recv(), Ok(1));
+
+    meister.befehl(Order::Boom, &thread_pool).unwrap();
+    assert_eq!(done_rx.recv(), Err(mpsc::RecvError));
+
+    match meister.befehl(Order::Value(2), &thread_pool) {
+        Err(crate::SendError { error: crate::Error::Panicked(message), .. }) =>
+            assert_eq!(message, "boom"),
+        other =>
+            panic!("expected Error::Panicked, got {other:?}"),
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    cell::{
+        RefCell,
+    },
+    time::{
+        Duration,
+    },
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    komm,
+    utils,
+};
+
+#[derive(Debug)]
+enum LocalOrder {
+    Zeug(komm::RundfunkZeug<usize>),
+    Abbrechen(komm::StreamAbbrechen),
+    Ende(komm::RundfunkEnde),
+}
+
+impl From<komm::RundfunkZeug<usize>> for LocalOrder {
+    fn from(rundfunk_zeug: komm::RundfunkZeug<usize>) -> LocalOrder {
+        LocalOrder::Zeug(rundfunk_zeug)
+    }
+}
+
+impl From<komm::StreamAbbrechen> for LocalOrder {
+    fn from(stream_abbrechen: komm::StreamAbbrechen) -> LocalOrder {
+        LocalOrder::Abbrechen(stream_abbrechen)
+    }
+}
+
+impl From<komm::RundfunkEnde> for LocalOrder {
+    fn from(rundfunk_ende: komm::RundfunkEnde) -> LocalOrder {
+        LocalOrder::Ende(rundfunk_ende)
+    }
+}
+
+#[test]
+fn multicast_and_last_unsubscribe() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let adapter_starten = || {
+        let (sync_tx, sync_rx) = mpsc::sync_channel(16);
+        let adapter =
+            utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+        let sendegeraet =
+            komm::Sendegeraet::starten(&adapter.sklave_meister, thread_pool.clone());
+        (adapter, sendegeraet, sync_rx)
+    };
+    let (_producer, producer_sendegeraet, producer_rx) = adapter_starten();
+    let (_a, a_sendegeraet, a_rx) = adapter_starten();
+    let (_b, b_sendegeraet, b_rx) = adapter_starten();
+    let timeout = Duration::from_millis(1000);
+    let short_timeout = Duration::from_millis(100);
+
+    let stream_erbauer = komm::StreamErbauer::default();
+    let rundfunk = producer_sendegeraet.rundfunk_starten::<usize>(&stream_erbauer);
+    // nobody has subscribed yet: broadcast stays alive
+    assert_eq!(rundfunk.senden(0), 0);
+    assert!(matches!(producer_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+
+    let a_abonnement = rundfunk.abonnieren(&a_sendegeraet).unwrap();
+    let b_abonnement = rundfunk.abonnieren(&b_sendegeraet).unwrap();
+    assert_eq!(a_abonnement.stream_id(), rundfunk.stream_id());
+    assert_eq!(rundfunk.abonnenten_count(), 2);
+
+    assert_eq!(rundfunk.senden(1), 2);
+    for rx in [&a_rx, &b_rx] {
+        assert!(matches!(
+            rx.recv_timeout(timeout),
+            Ok(LocalOrder::Zeug(komm::RundfunkZeug { zeug: 1, ref stream_id, })) if stream_id == rundfunk.stream_id(),
+        ));
+    }
+
+    drop(a_abonnement);
+    assert_eq!(rundfunk.senden(2), 1);
+    assert!(matches!(b_rx.recv_timeout(timeout), Ok(LocalOrder::Zeug(komm::RundfunkZeug { zeug: 2, .. }))));
+    assert!(matches!(a_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+    assert!(matches!(producer_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+
+    drop(b_abonnement);
+    assert_eq!(rundfunk.abonnenten_count(), 0);
+    assert!(matches!(
+        producer_rx.recv_timeout(timeout),
+        Ok(LocalOrder::Abbrechen(komm::StreamAbbrechen { ref stream_id, })) if stream_id == rundfunk.stream_id(),
+    ));
+    assert_eq!(rundfunk.senden(3), 0);
+    assert!(matches!(producer_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+
+    // broadcast has been cancelled, late subscribers are rejected
+    assert!(rundfunk.abonnieren(&a_sendegeraet).is_none());
+}
+
+#[test]
+fn zustellen_outside_lock() {
+    thread_local! {
+        static RUNDFUNK: RefCell<Option<komm::Rundfunk<usize>>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Debug)]
+    enum ReentrantOrder {
+        Zeug { zeug: usize, abonnenten_count: usize, },
+        Abbrechen,
+        Ende,
+    }
+
+    impl From<komm::RundfunkZeug<usize>> for ReentrantOrder {
+        fn from(rundfunk_zeug: komm::RundfunkZeug<usize>) -> ReentrantOrder {
+            // delivery must not hold the subscribers lock
+            let abonnenten_count = RUNDFUNK.with(|rundfunk| rundfunk.borrow().as_ref().unwrap().abonnenten_count());
+            ReentrantOrder::Zeug { zeug: rundfunk_zeug.zeug, abonnenten_count, }
+        }
+    }
+
+    impl From<komm::StreamAbbrechen> for ReentrantOrder {
+        fn from(_stream_abbrechen: komm::StreamAbbrechen) -> ReentrantOrder {
+            ReentrantOrder::Abbrechen
+        }
+    }
+
+    impl From<komm::RundfunkEnde> for ReentrantOrder {
+        fn from(_rundfunk_ende: komm::RundfunkEnde) -> ReentrantOrder {
+            ReentrantOrder::Ende
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (sync_tx, sync_rx) = mpsc::sync_channel(16);
+    let adapter =
+        utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+    let sendegeraet =
+        komm::Sendegeraet::starten(&adapter.sklave_meister, thread_pool.clone());
+
+    let stream_erbauer = komm::StreamErbauer::default();
+    let rundfunk = sendegeraet.rundfunk_starten::<usize>(&stream_erbauer);
+    RUNDFUNK.with(|cell| *cell.borrow_mut() = Some(rundfunk.clone()));
+    let _abonnement = rundfunk.abonnieren(&sendegeraet).unwrap();
+
+    assert_eq!(rundfunk.senden(7), 1);
+    assert!(matches!(
+        sync_rx.recv_timeout(Duration::from_millis(1000)),
+        Ok(ReentrantOrder::Zeug { zeug: 7, abonnenten_count: 1, }),
+    ));
+    RUNDFUNK.with(|cell| cell.borrow_mut().take());
+}
+
+#[test]
+fn ende_on_last_rundfunk_drop() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let adapter_starten = || {
+        let (sync_tx, sync_rx) = mpsc::sync_channel(16);
+        let adapter =
+            utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+        let sendegeraet =
+            komm::Sendegeraet::starten(&adapter.sklave_meister, thread_pool.clone());
+        (adapter, sendegeraet, sync_rx)
+    };
+    let (_producer, producer_sendegeraet, producer_rx) = adapter_starten();
+    let (_a, a_sendegeraet, a_rx) = adapter_starten();
+    let (_b, b_sendegeraet, b_rx) = adapter_starten();
+    let timeout = Duration::from_millis(1000);
+    let short_timeout = Duration::from_millis(100);
+
+    let stream_erbauer = komm::StreamErbauer::default();
+    let rundfunk = producer_sendegeraet.rundfunk_starten::<usize>(&stream_erbauer);
+    let stream_id = rundfunk.stream_id().clone();
+    let rundfunk_clone = rundfunk.clone();
+    let a_abonnement = rundfunk.abonnieren(&a_sendegeraet).unwrap();
+    let b_abonnement = rundfunk.abonnieren(&b_sendegeraet).unwrap();
+
+    drop(rundfunk);
+    assert!(matches!(a_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+    assert_eq!(rundfunk_clone.senden(1), 2);
+    for rx in [&a_rx, &b_rx] {
+        assert!(matches!(rx.recv_timeout(timeout), Ok(LocalOrder::Zeug(komm::RundfunkZeug { zeug: 1, .. }))));
+    }
+
+    drop(rundfunk_clone);
+    for rx in [&a_rx, &b_rx] {
+        assert!(matches!(
+            rx.recv_timeout(timeout),
+            Ok(LocalOrder::Ende(komm::RundfunkEnde { stream_id: ref ende_stream_id, })) if ende_stream_id == &stream_id,
+        ));
+    }
+    // subscribers leaving after the end do not cancel anything
+    drop(a_abonnement);
+    drop(b_abonnement);
+    assert!(matches!(producer_rx.recv_timeout(short_timeout), Err(mpsc::RecvTimeoutError::Timeout)));
+}
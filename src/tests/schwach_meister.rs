@@ -0,0 +1,67 @@
+use std::{
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    Error,
+    Freie,
+    SendError,
+    SklaveJob,
+    TerminationReason,
+};
+
+#[test]
+fn upgrade_and_befehl() {
+    struct Welt {
+        values_tx: mpsc::Sender<usize>,
+    }
+
+    struct Order(usize);
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, Order(value)| welt.values_tx.send(value).unwrap())
+                .unwrap();
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { values_tx, }, &thread_pool)
+        .unwrap();
+    let schwach_meister = meister.downgrade();
+    assert_eq!(schwach_meister.actor_id(), meister.actor_id());
+
+    schwach_meister.befehl(Order(1), &thread_pool).unwrap();
+    assert_eq!(values_rx.recv(), Ok(1));
+
+    let upgraded = schwach_meister.upgrade().unwrap();
+    upgraded.befehl(Order(2), &thread_pool).unwrap();
+    assert_eq!(values_rx.recv(), Ok(2));
+
+    drop(upgraded);
+    drop(meister);
+    // sklave has been resting, so the last strong reference is gone
+    assert_eq!(values_rx.recv(), Err(mpsc::RecvError));
+    assert!(schwach_meister.upgrade().is_none());
+    assert!(matches!(
+        schwach_meister.befehl(Order(3), &thread_pool),
+        Err(SendError { order: Order(3), error: Error::Terminated(TerminationReason::SklavenweltDropped), .. }),
+    ));
+}
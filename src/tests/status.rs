@@ -0,0 +1,128 @@
+use std::{
+    sync::{
+        mpsc,
+        Mutex,
+    },
+};
+
+use crate::{
+    ewig,
+    Freie,
+    Meister,
+    Status,
+    SklaveJob,
+    TerminationReason,
+};
+
+struct Welt {
+    gate_rx: Mutex<mpsc::Receiver<()>>,
+}
+
+struct Order;
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, Order| welt.gate_rx.lock().unwrap().recv().unwrap())
+            .unwrap();
+    }
+}
+
+fn wait_status(meister: &Meister<Welt, Order>, expected: Status) {
+    let backoff = crossbeam::utils::Backoff::new();
+    while meister.status() != expected {
+        backoff.snooze();
+    }
+}
+
+#[test]
+fn snapshot() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (gate_tx, gate_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { gate_rx: Mutex::new(gate_rx), }, &thread_pool)
+        .unwrap();
+    let resting = Status { orders_count: 0, is_resting: true, is_shutdown: false, terminated: None, };
+    wait_status(&meister, resting);
+
+    meister.befehl(Order, &thread_pool).unwrap();
+    wait_status(&meister, Status { orders_count: 0, is_resting: false, ..resting });
+    meister.befehle([Order, Order], &thread_pool).unwrap();
+    assert_eq!(meister.status(), Status { orders_count: 2, is_resting: false, ..resting });
+
+    for _ in 0 .. 3 {
+        gate_tx.send(()).unwrap();
+    }
+    wait_status(&meister, resting);
+
+    meister.beenden().unwrap();
+    assert_eq!(
+        meister.status(),
+        Status { is_resting: false, is_shutdown: true, terminated: Some(TerminationReason::Shutdown), ..resting },
+    );
+}
+
+#[test]
+fn ewig_snapshot() {
+    let meister = ewig::Freie::<usize, ewig::Error>::new()
+        .versklaven(|_sklave| Ok(()))
+        .unwrap();
+    let backoff = crossbeam::utils::Backoff::new();
+    while meister.status().terminated.is_none() {
+        backoff.snooze();
+    }
+    assert_eq!(
+        meister.status(),
+        ewig::Status { orders_count: 0, is_resting: false, terminated: Some(TerminationReason::JobDropped), },
+    );
+}
+
+#[test]
+fn ewig_resting() {
+    let (gate_tx, gate_rx) = mpsc::channel();
+    let meister = ewig::Freie::<usize, ewig::Error>::new()
+        .versklaven(move |sklave| {
+            loop {
+                for value in sklave.zu_ihren_diensten()? {
+                    gate_rx.recv().unwrap();
+                    if value == 0 {
+                        return Ok(());
+                    }
+                }
+            }
+        })
+        .unwrap();
+    let wait_resting = |is_resting| {
+        let backoff = crossbeam::utils::Backoff::new();
+        while meister.status().is_resting != is_resting {
+            backoff.snooze();
+        }
+    };
+
+    wait_resting(true);
+    meister.befehl(1).unwrap();
+    wait_resting(false);
+    assert_eq!(meister.status(), ewig::Status { orders_count: 0, is_resting: false, terminated: None, });
+    gate_tx.send(()).unwrap();
+    wait_resting(true);
+
+    meister.befehl(0).unwrap();
+    gate_tx.send(()).unwrap();
+    let backoff = crossbeam::utils::Backoff::new();
+    while meister.status().terminated.is_none() {
+        backoff.snooze();
+    }
+    assert!(!meister.status().is_resting);
+}
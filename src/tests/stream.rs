@@ -223,6 +223,36 @@ fn basic() {
     assert_eq!(rx.recv(), Ok(vec![-3, -2, -1, 0, 1, 2, 3, 4, 5, 6]));
 }
 
+#[test]
+fn leser() {
+    type StreamInhalt = komm::Zusage<komm::Streamzeug<isize>>;
+    type LocalJob = stream::Job<StreamInhalt>;
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<LocalJob, stream::JobUnit<StreamInhalt, LocalJob>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let stream_meister = stream::start(&thread_pool);
+    let stream_sendegeraet = komm::Sendegeraet::starten(&stream_meister, thread_pool.clone());
+    let stream_erbauer = komm::StreamErbauer::default();
+
+    let lesen = |start, end| {
+        stream_sendegeraet
+            .stream_lesen(
+                &stream_erbauer,
+                |stream_echo| stream::OrderStreamStart { start, end, stream_echo, },
+                |stream_echo| stream::OrderStreamNext { stream_echo, },
+            )
+            .unwrap()
+    };
+
+    assert_eq!(lesen(3, 6).collect::<Vec<_>>(), vec![3, 4, 5]);
+    assert_eq!(lesen(9, 10).collect::<Vec<_>>(), vec![9]);
+    assert_eq!(lesen(1, 1).collect::<Vec<_>>(), Vec::<isize>::new());
+    assert_eq!(lesen(-1, 1).collect::<Vec<_>>(), vec![-1, 0]);
+}
+
 #[allow(clippy::module_inception)]
 mod stream {
     use crate::{
@@ -385,7 +415,7 @@ mod stream {
 
     pub fn start<S, J>(thread_pool: &edeltraud::Handle<J>) -> Meister<Welt, Order<S>>
     where S: komm::Echo<komm::Streamzeug<isize>>,
-          J: From<SklaveJob<Welt, Order<S>>>,
+          J: From<SklaveJob<Welt, Order<S>>> + Send + 'static,
     {
         let freie = Freie::new();
         freie.versklaven(Welt::default(), thread_pool).unwrap()
@@ -0,0 +1,200 @@
+use std::{
+    time::{
+        Duration,
+    },
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    komm::{
+        self,
+        Echo as _,
+    },
+    Freie,
+    SklaveJob,
+};
+
+#[derive(Clone)]
+struct Echo(mpsc::Sender<komm::Streamzeug<usize>>);
+
+impl komm::Echo<komm::Streamzeug<usize>> for Echo {
+    fn commit_echo(self, inhalt: komm::Streamzeug<usize>) -> Result<(), komm::EchoError<komm::Streamzeug<usize>>> {
+        self.0.send(inhalt)
+            .map_err(|mpsc::SendError(inhalt)| komm::EchoError { inhalt, })
+    }
+}
+
+struct Start {
+    start: usize,
+    end: usize,
+    echo: Echo,
+}
+
+enum Order {
+    Start(komm::StreamStarten<Start>),
+    Mehr(komm::StreamMehr<()>),
+    Abbrechen(komm::StreamAbbrechen),
+}
+
+impl From<komm::StreamStarten<Start>> for Order {
+    fn from(order: komm::StreamStarten<Start>) -> Order {
+        Order::Start(order)
+    }
+}
+
+impl From<komm::StreamMehr<()>> for Order {
+    fn from(order: komm::StreamMehr<()>) -> Order {
+        Order::Mehr(order)
+    }
+}
+
+impl From<komm::StreamAbbrechen> for Order {
+    fn from(order: komm::StreamAbbrechen) -> Order {
+        Order::Abbrechen(order)
+    }
+}
+
+#[derive(Default)]
+struct Welt {
+    maybe_stream: Option<(komm::StreamToken, Start)>,
+    maybe_fremd_tx: Option<mpsc::Sender<komm::StreamId>>,
+}
+
+impl Welt {
+    fn pumpen(&mut self) {
+        let Some((mut stream_token, mut start)) = self.maybe_stream.take() else {
+            return;
+        };
+        while start.start < start.end {
+            match stream_token.streamzeug_zeug_vorab(start.start) {
+                Some(streamzeug) => {
+                    start.echo.clone().commit_echo(streamzeug).unwrap();
+                    start.start += 1;
+                },
+                None => {
+                    self.maybe_stream = Some((stream_token, start));
+                    return;
+                },
+            }
+        }
+        start.echo.commit_echo(stream_token.streamzeug_nicht_mehr()).unwrap();
+    }
+}
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, order| {
+                match order {
+                    Order::Start(komm::StreamStarten { inhalt, stream_token, }) =>
+                        welt.maybe_stream = Some((stream_token, inhalt)),
+                    Order::Mehr(komm::StreamMehr { inhalt: (), stream_token, }) =>
+                        if let Some((current_token, _)) = welt.maybe_stream.as_mut()
+                            && let Err(fremd_token) = current_token.credits_aufnehmen(stream_token)
+                            && let Some(fremd_tx) = welt.maybe_fremd_tx.as_ref()
+                        {
+                            fremd_tx.send(fremd_token.stream_id().clone()).ok();
+                        },
+                    Order::Abbrechen(komm::StreamAbbrechen { .. }) =>
+                        welt.maybe_stream = None,
+                }
+                welt.pumpen();
+            })
+            .unwrap();
+    }
+}
+
+#[test]
+fn windowed() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(Welt::default(), &thread_pool)
+        .unwrap();
+    let sendegeraet = komm::Sendegeraet::starten(&meister, thread_pool.clone());
+    let stream_erbauer = komm::StreamErbauer::default();
+
+    let (echo_tx, echo_rx) = mpsc::channel();
+    let stream = sendegeraet
+        .stream_starten_mit_credits(&stream_erbauer, Start { start: 0, end: 10, echo: Echo(echo_tx), }, 4)
+        .unwrap();
+
+    let empfangen = |count, quittungen: &mut Vec<komm::StreamToken>| {
+        (0 .. count)
+            .map(|_| match echo_rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+                komm::Streamzeug::Zeug { zeug, mehr, } => {
+                    assert_eq!(mehr.stream_id(), stream.stream_id());
+                    quittungen.push(komm::StreamToken::from(mehr));
+                    Some(zeug)
+                },
+                komm::Streamzeug::NichtMehr(..) | komm::Streamzeug::Fehler { .. } =>
+                    None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut quittungen = Vec::new();
+    assert_eq!(empfangen(4, &mut quittungen), vec![Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(echo_rx.recv_timeout(Duration::from_millis(100)).err(), Some(mpsc::RecvTimeoutError::Timeout));
+
+    // receipt tokens carry no credits: returning them does not widen the window
+    for quittung in quittungen.drain(..) {
+        assert_eq!(quittung.credits(), 0);
+        stream.mehr((), quittung).unwrap();
+    }
+    assert_eq!(echo_rx.recv_timeout(Duration::from_millis(100)).err(), Some(mpsc::RecvTimeoutError::Timeout));
+
+    stream.credits_gewaehren((), 3).unwrap();
+    assert_eq!(empfangen(3, &mut quittungen), vec![Some(4), Some(5), Some(6)]);
+
+    stream.credits_gewaehren((), 16).unwrap();
+    assert_eq!(empfangen(4, &mut quittungen), vec![Some(7), Some(8), Some(9), None]);
+}
+
+#[test]
+fn fremder_token() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (fremd_tx, fremd_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { maybe_stream: None, maybe_fremd_tx: Some(fremd_tx), }, &thread_pool)
+        .unwrap();
+    let sendegeraet = komm::Sendegeraet::starten(&meister, thread_pool.clone());
+    let stream_erbauer = komm::StreamErbauer::default();
+
+    let (echo_a_tx, echo_a_rx) = mpsc::channel();
+    let stream_a = sendegeraet
+        .stream_starten_mit_credits(&stream_erbauer, Start { start: 0, end: 10, echo: Echo(echo_a_tx), }, 1)
+        .unwrap();
+    let Ok(komm::Streamzeug::Zeug { zeug: 0, mehr, }) = echo_a_rx.recv_timeout(Duration::from_secs(5)) else {
+        panic!("expected first zeug of stream a");
+    };
+
+    // welt now serves stream b only
+    let (echo_b_tx, echo_b_rx) = mpsc::channel();
+    let _stream_b = sendegeraet
+        .stream_starten_mit_credits(&stream_erbauer, Start { start: 100, end: 110, echo: Echo(echo_b_tx), }, 1)
+        .unwrap();
+    assert!(matches!(echo_b_rx.recv_timeout(Duration::from_secs(5)), Ok(komm::Streamzeug::Zeug { zeug: 100, .. })));
+
+    stream_a.credits_gewaehren((), 1).unwrap();
+    stream_a.mehr((), mehr.into()).unwrap();
+    assert_eq!(fremd_rx.recv_timeout(Duration::from_secs(5)).as_ref(), Ok(stream_a.stream_id()));
+    assert_eq!(fremd_rx.recv_timeout(Duration::from_secs(5)).as_ref(), Ok(stream_a.stream_id()));
+}
@@ -0,0 +1,73 @@
+use std::{
+    time::{
+        Duration,
+    },
+    sync::{
+        mpsc,
+    },
+};
+
+use crate::{
+    komm,
+    utils,
+};
+
+#[derive(Debug)]
+enum LocalOrder {
+    Starten(komm::StreamStarten<()>),
+    Abbrechen(komm::StreamAbbrechen),
+}
+
+impl From<komm::StreamStarten<()>> for LocalOrder {
+    fn from(stream_starten: komm::StreamStarten<()>) -> LocalOrder {
+        LocalOrder::Starten(stream_starten)
+    }
+}
+
+impl From<komm::StreamAbbrechen> for LocalOrder {
+    fn from(stream_abbrechen: komm::StreamAbbrechen) -> LocalOrder {
+        LocalOrder::Abbrechen(stream_abbrechen)
+    }
+}
+
+#[test]
+fn fehler_ends_stream() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (sync_tx, sync_rx) = mpsc::sync_channel(0);
+    let adapter =
+        utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+    let sendegeraet =
+        komm::Sendegeraet::starten(&adapter.sklave_meister, thread_pool.clone());
+    let stream_erbauer = komm::StreamErbauer::default();
+    let timeout = Duration::from_millis(1000);
+
+    let stream = sendegeraet.stream_starten(&stream_erbauer, ()).unwrap();
+    let Ok(LocalOrder::Starten(komm::StreamStarten { inhalt: (), stream_token, })) = sync_rx.recv_timeout(timeout) else {
+        panic!("expected StreamStarten");
+    };
+    let streamzeug: komm::Streamzeug<usize, &str> = stream_token.streamzeug_zeug(1).fehlbar();
+    let komm::Streamzeug::Zeug { zeug: 1, mehr, } = streamzeug else {
+        panic!("expected Streamzeug::Zeug");
+    };
+    let stream_token: komm::StreamToken = mehr.into();
+    let streamzeug: komm::Streamzeug<usize, &str> = stream_token.streamzeug_fehler("parse error");
+    assert!(matches!(
+        streamzeug,
+        komm::Streamzeug::Fehler { fehler: "parse error", ref nicht_mehr, } if nicht_mehr.stream_id() == stream.stream_id(),
+    ));
+    drop(stream);
+    assert!(matches!(sync_rx.recv_timeout(Duration::from_millis(100)), Err(mpsc::RecvTimeoutError::Timeout)));
+
+    let stream = sendegeraet.stream_starten(&stream_erbauer, ()).unwrap();
+    let stream_id = stream.stream_id().clone();
+    assert!(matches!(sync_rx.recv_timeout(timeout), Ok(LocalOrder::Starten(..))));
+    drop(stream);
+    assert!(matches!(
+        sync_rx.recv_timeout(timeout),
+        Ok(LocalOrder::Abbrechen(komm::StreamAbbrechen { stream_id: dropped_id, })) if dropped_id == stream_id,
+    ));
+}
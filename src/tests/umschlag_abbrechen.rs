@@ -44,3 +44,115 @@ fn basic() {
         Ok(LocalOrder(komm::UmschlagAbbrechen { stamp: LocalStamp, })),
     ));
 }
+
+#[test]
+fn commit_terminated() {
+    struct LocalStamp(usize);
+
+    #[allow(dead_code)]
+    enum LocalOrder {
+        Umschlag(komm::Umschlag<&'static str, LocalStamp>),
+        Abbrechen(komm::UmschlagAbbrechen<LocalStamp>),
+    }
+
+    impl From<komm::Umschlag<&'static str, LocalStamp>> for LocalOrder {
+        fn from(umschlag: komm::Umschlag<&'static str, LocalStamp>) -> LocalOrder {
+            LocalOrder::Umschlag(umschlag)
+        }
+    }
+
+    impl From<komm::UmschlagAbbrechen<LocalStamp>> for LocalOrder {
+        fn from(umschlag_abbrechen: komm::UmschlagAbbrechen<LocalStamp>) -> LocalOrder {
+            LocalOrder::Abbrechen(umschlag_abbrechen)
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (sync_tx, _sync_rx) = mpsc::sync_channel::<LocalOrder>(0);
+    let adapter =
+        utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+
+    let sendegeraet =
+        komm::Sendegeraet::starten(
+            &adapter.sklave_meister,
+            #[allow(clippy::redundant_clone)]
+            thread_pool.clone(),
+        );
+    let rueckkopplung = sendegeraet.rueckkopplung(LocalStamp(17));
+    adapter.sklave_meister.beenden().unwrap();
+
+    assert!(matches!(
+        rueckkopplung.commit("hello"),
+        Err(komm::CommitError {
+            inhalt: "hello",
+            maybe_stamp: Some(LocalStamp(17)),
+            error: komm::CommitErrorKind::Send(crate::Error::Terminated(crate::TerminationReason::Shutdown)),
+            ..
+        }),
+    ));
+}
+
+#[test]
+fn frist_abgelaufen() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct LocalStamp(usize);
+
+    #[derive(PartialEq, Eq, Debug)]
+    enum LocalOrder {
+        Umschlag(usize, LocalStamp),
+        Abbrechen(LocalStamp),
+        FristAbgelaufen(LocalStamp),
+    }
+
+    impl From<komm::Umschlag<usize, LocalStamp>> for LocalOrder {
+        fn from(umschlag: komm::Umschlag<usize, LocalStamp>) -> LocalOrder {
+            LocalOrder::Umschlag(umschlag.inhalt, umschlag.stamp)
+        }
+    }
+
+    impl From<komm::UmschlagAbbrechen<LocalStamp>> for LocalOrder {
+        fn from(umschlag_abbrechen: komm::UmschlagAbbrechen<LocalStamp>) -> LocalOrder {
+            LocalOrder::Abbrechen(umschlag_abbrechen.stamp)
+        }
+    }
+
+    impl From<komm::UmschlagFristAbgelaufen<LocalStamp>> for LocalOrder {
+        fn from(umschlag_frist_abgelaufen: komm::UmschlagFristAbgelaufen<LocalStamp>) -> LocalOrder {
+            LocalOrder::FristAbgelaufen(umschlag_frist_abgelaufen.stamp)
+        }
+    }
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, utils::mpsc_forward_adapter::JobUnit<_, _>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (sync_tx, sync_rx) = mpsc::sync_channel(0);
+    let adapter =
+        utils::mpsc_forward_adapter::Adapter::versklaven(sync_tx, &thread_pool).unwrap();
+
+    let sendegeraet =
+        komm::Sendegeraet::starten(&adapter.sklave_meister, thread_pool.clone());
+    let timeout = std::time::Duration::from_millis(1000);
+
+    let rueckkopplung = sendegeraet.rueckkopplung_mit_frist(LocalStamp(1), std::time::Duration::from_millis(20));
+    assert_eq!(sync_rx.recv_timeout(timeout), Ok(LocalOrder::FristAbgelaufen(LocalStamp(1))));
+    assert!(matches!(
+        rueckkopplung.commit(17),
+        Err(komm::CommitError { inhalt: 17, maybe_stamp: None, error: komm::CommitErrorKind::FristAbgelaufen, .. }),
+    ));
+
+    let rueckkopplung = sendegeraet.rueckkopplung_mit_frist(LocalStamp(2), std::time::Duration::from_millis(50));
+    rueckkopplung.commit(19).unwrap();
+    assert_eq!(sync_rx.recv_timeout(timeout), Ok(LocalOrder::Umschlag(19, LocalStamp(2))));
+
+    let rueckkopplung = sendegeraet.rueckkopplung_mit_frist(LocalStamp(3), std::time::Duration::from_millis(50));
+    drop(rueckkopplung);
+    assert_eq!(sync_rx.recv_timeout(timeout), Ok(LocalOrder::Abbrechen(LocalStamp(3))));
+
+    assert_eq!(sync_rx.recv_timeout(std::time::Duration::from_millis(200)), Err(mpsc::RecvTimeoutError::Timeout));
+}
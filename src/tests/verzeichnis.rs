@@ -0,0 +1,100 @@
+use std::{
+    sync::{
+        mpsc,
+        Arc,
+    },
+};
+
+use crate::{
+    verzeichnis::{
+        self,
+        Verzeichnis,
+    },
+    Freie,
+    SklaveJob,
+};
+
+struct Welt {
+    values_tx: mpsc::Sender<usize>,
+}
+
+struct Order(usize);
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, Order(value)| welt.values_tx.send(value).unwrap())
+            .unwrap();
+    }
+}
+
+#[test]
+fn lookup_and_removal() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let verzeichnis = Verzeichnis::new();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { values_tx: values_tx.clone(), }, &thread_pool)
+        .unwrap();
+    verzeichnis.registrieren("worker", &meister, thread_pool.clone()).unwrap();
+
+    assert!(matches!(
+        verzeichnis.registrieren("worker", &meister, thread_pool.clone()),
+        Err(verzeichnis::Error::AlreadyRegistered(kennung)) if kennung.actor_id == meister.actor_id(),
+    ));
+    assert!(verzeichnis.finden::<usize>("worker").is_none());
+    assert!(verzeichnis.finden::<Order>("unknown").is_none());
+
+    let sendegeraet = verzeichnis.finden::<Order>("worker").unwrap();
+    sendegeraet.befehl(Order(1)).unwrap();
+    assert_eq!(values_rx.recv(), Ok(1));
+    let sendegeraet = verzeichnis.finden_nach_id::<Order>(meister.actor_id()).unwrap();
+    sendegeraet.befehl(Order(2)).unwrap();
+    assert_eq!(values_rx.recv(), Ok(2));
+
+    meister.beenden().unwrap();
+    assert!(verzeichnis.finden::<Order>("worker").is_none());
+    assert!(verzeichnis.finden_nach_id::<Order>(meister.actor_id()).is_none());
+
+    let meister = Freie::new()
+        .versklaven(Welt { values_tx, }, &thread_pool)
+        .unwrap();
+    verzeichnis.registrieren("worker", &meister, thread_pool.clone()).unwrap();
+    verzeichnis.finden::<Order>("worker").unwrap().befehl(Order(3)).unwrap();
+    assert_eq!(values_rx.recv(), Ok(3));
+}
+
+#[test]
+fn removal_on_termination() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let verzeichnis = Verzeichnis::new();
+
+    let (values_tx, _values_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { values_tx, }, &thread_pool)
+        .unwrap();
+    let weak_count = Arc::weak_count(&meister.inner);
+    verzeichnis.registrieren("worker", &meister, thread_pool.clone()).unwrap();
+    assert!(Arc::weak_count(&meister.inner) > weak_count);
+
+    // entry is removed by termination itself, not by a later lookup
+    meister.beenden().unwrap();
+    assert_eq!(Arc::weak_count(&meister.inner), weak_count);
+}
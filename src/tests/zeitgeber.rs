@@ -0,0 +1,282 @@
+use std::{
+    time::{
+        Instant,
+        Duration,
+    },
+    sync::{
+        mpsc,
+        Mutex,
+    },
+};
+
+use crate::{
+    komm,
+    zeitgeber::{
+        self,
+        IntervallError,
+        IntervallPolicy,
+    },
+    DeadLetter,
+    Error,
+    Freie,
+    SendError,
+    SklaveJob,
+};
+
+#[derive(PartialEq, Eq, Debug)]
+struct Order(usize);
+
+struct Welt {
+    values_tx: mpsc::Sender<(usize, Instant)>,
+}
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, Order(value)| welt.values_tx.send((value, Instant::now())).unwrap())
+            .unwrap();
+    }
+}
+
+#[test]
+fn befehl_nach_und_abbrechen() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie.versklaven(Welt { values_tx, }, &thread_pool).unwrap();
+
+    let now = Instant::now();
+    sendegeraet.befehl_nach(Duration::from_millis(60), Order(1)).unwrap();
+    let wecker = sendegeraet.befehl_nach(Duration::from_secs(5), Order(2)).unwrap();
+    sendegeraet.befehl_um(now + Duration::from_millis(40), Order(3)).unwrap();
+    sendegeraet.befehl_um(now, Order(4)).unwrap();
+    assert_eq!(wecker.abbrechen(), Some(Order(2)));
+    assert_eq!(wecker.abbrechen(), None);
+
+    let (value, _) = values_rx.recv().unwrap();
+    assert_eq!(value, 4);
+    let (value, fired_at) = values_rx.recv().unwrap();
+    assert_eq!(value, 3);
+    assert!(fired_at >= now + Duration::from_millis(40));
+    let (value, fired_at) = values_rx.recv().unwrap();
+    assert_eq!(value, 1);
+    assert!(fired_at >= now + Duration::from_millis(60));
+}
+
+#[test]
+fn intervall_stoppt() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let meister = freie.versklaven(Welt { values_tx, }, &thread_pool).unwrap();
+
+    let period = Duration::from_millis(10);
+    let (alive_tx, alive_rx) = mpsc::channel::<()>();
+    let now = Instant::now();
+    let mut counter = 0;
+    sendegeraet
+        .intervall(period, IntervallPolicy::FixedRate, move || {
+            let _alive_tx = &alive_tx;
+            counter += 1;
+            Order(counter)
+        })
+        .unwrap();
+    for expected in 1 ..= 3 {
+        let (value, fired_at) = values_rx.recv().unwrap();
+        assert_eq!(value, expected);
+        assert!(fired_at >= now + period * expected as u32);
+    }
+
+    drop(meister);
+    assert_eq!(alive_rx.recv_timeout(Duration::from_secs(5)), Err(mpsc::RecvTimeoutError::Disconnected));
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie.versklaven(Welt { values_tx, }, &thread_pool).unwrap();
+
+    let (alive_tx, alive_rx) = mpsc::channel::<()>();
+    let intervall = sendegeraet
+        .intervall(period, IntervallPolicy::FixedDelay, move || {
+            let _alive_tx = &alive_tx;
+            Order(0)
+        })
+        .unwrap();
+    let (value, _) = values_rx.recv().unwrap();
+    assert_eq!(value, 0);
+    intervall.abbrechen();
+    assert_eq!(alive_rx.recv_timeout(Duration::from_secs(5)), Err(mpsc::RecvTimeoutError::Disconnected));
+}
+
+struct GateWelt {
+    gate_rx: Mutex<mpsc::Receiver<()>>,
+    started_tx: mpsc::Sender<usize>,
+}
+
+struct GateJobUnit<J>(edeltraud::JobUnit<J, SklaveJob<GateWelt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<GateWelt, Order>>> for GateJobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<GateWelt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for GateJobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|welt, Order(value)| {
+                welt.started_tx.send(value).ok();
+                welt.gate_rx.lock().unwrap().recv().ok();
+            })
+            .unwrap();
+    }
+}
+
+#[test]
+fn befehl_um_voll() {
+    let gate_edeltraud = edeltraud::Builder::new()
+        .build::<_, GateJobUnit<_>>()
+        .unwrap();
+    let gate_thread_pool = gate_edeltraud.handle();
+
+    let (gate_tx, gate_rx) = mpsc::channel();
+    let (started_tx, started_rx) = mpsc::channel();
+    let (dead_tx, dead_rx) = mpsc::channel();
+    let dead_tx = Mutex::new(dead_tx);
+    let gate_freie = Freie::with_capacity(1)
+        .with_dead_letters(move |DeadLetter { order: Order(value), .. }| {
+            dead_tx.lock().unwrap().send(value).ok();
+        });
+    let gate_sendegeraet = komm::Sendegeraet::starten(&gate_freie.meister(), gate_thread_pool.clone());
+    let gate_meister = gate_freie
+        .versklaven(GateWelt { gate_rx: Mutex::new(gate_rx), started_tx, }, &gate_thread_pool)
+        .unwrap();
+    gate_meister.befehl(Order(0), &gate_thread_pool).unwrap();
+    started_rx.recv().unwrap();
+    while let Ok(()) = gate_meister.try_befehl(Order(0), &gate_thread_pool) { }
+    assert!(matches!(gate_sendegeraet.try_befehl(Order(0)), Err(SendError { error: Error::Full, .. })));
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie.versklaven(Welt { values_tx, }, &thread_pool).unwrap();
+
+    gate_sendegeraet.befehl_nach(Duration::from_millis(1), Order(1)).unwrap();
+    sendegeraet.befehl_nach(Duration::from_millis(10), Order(2)).unwrap();
+
+    let (value, _) = values_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(value, 2);
+
+    drop(gate_tx);
+    while started_rx.recv_timeout(Duration::from_secs(5)).unwrap() != 1 { }
+    assert_eq!(dead_rx.try_recv(), Err(mpsc::TryRecvError::Empty));
+}
+
+#[test]
+fn intervall_voll() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, GateJobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (gate_tx, gate_rx) = mpsc::channel();
+    let (started_tx, started_rx) = mpsc::channel();
+    let freie = Freie::with_capacity(1);
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie
+        .versklaven(GateWelt { gate_rx: Mutex::new(gate_rx), started_tx, }, &thread_pool)
+        .unwrap();
+
+    sendegeraet
+        .intervall(Duration::from_millis(1), IntervallPolicy::FixedRate, || Order(0))
+        .unwrap();
+    started_rx.recv().unwrap();
+
+    let other_edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let other_thread_pool = other_edeltraud.handle();
+    let (values_tx, values_rx) = mpsc::channel();
+    let other_freie = Freie::new();
+    let other_sendegeraet = komm::Sendegeraet::starten(&other_freie.meister(), other_thread_pool.clone());
+    let _other_meister = other_freie.versklaven(Welt { values_tx, }, &other_thread_pool).unwrap();
+
+    // mailbox stays full for many ticks while the sklave is blocked, other timers keep firing
+    other_sendegeraet.befehl_nach(Duration::from_millis(20), Order(1)).unwrap();
+    let (value, _) = values_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(value, 1);
+    gate_tx.send(()).unwrap();
+    gate_tx.send(()).unwrap();
+    started_rx.recv().unwrap();
+    // intervall survived the full mailbox and keeps delivering
+    gate_tx.send(()).unwrap();
+    started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    drop(gate_tx);
+}
+
+#[test]
+fn intervall_panic() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (values_tx, values_rx) = mpsc::channel();
+    let freie = Freie::new();
+    let sendegeraet = komm::Sendegeraet::starten(&freie.meister(), thread_pool.clone());
+    let _meister = freie.versklaven(Welt { values_tx, }, &thread_pool).unwrap();
+
+    assert!(matches!(
+        sendegeraet.intervall(Duration::ZERO, IntervallPolicy::FixedRate, || Order(0)),
+        Err(IntervallError::ZeroPeriod),
+    ));
+
+    let (alive_tx, alive_rx) = mpsc::channel::<()>();
+    sendegeraet
+        .intervall(Duration::from_millis(1), IntervallPolicy::FixedRate, move || {
+            let _alive_tx = &alive_tx;
+            panic!("make_order failed")
+        })
+        .unwrap();
+    assert_eq!(alive_rx.recv_timeout(Duration::from_secs(5)), Err(mpsc::RecvTimeoutError::Disconnected));
+
+    // zeitgeber survived the panicking subscription
+    sendegeraet.befehl_nach(Duration::from_millis(1), Order(1)).unwrap();
+    let (value, _) = values_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn planung_abbrechen() {
+    let (alive_tx, alive_rx) = mpsc::channel::<()>();
+    let abbrecher = zeitgeber::planen(zeitgeber::Planung {
+        frist: Instant::now() + Duration::from_secs(60),
+        aktion: Box::new(move || { let _alive_tx = &alive_tx; }),
+    }).unwrap();
+    abbrecher.abbrechen();
+    // entry is removed from the wheel and its aktion is dropped without firing
+    assert_eq!(alive_rx.recv_timeout(Duration::from_secs(5)), Err(mpsc::RecvTimeoutError::Disconnected));
+}
@@ -0,0 +1,99 @@
+use std::{
+    pin::{
+        pin,
+    },
+    future::{
+        Future,
+    },
+    task::{
+        self,
+        Poll,
+        Wake,
+    },
+    thread,
+    sync::{
+        Arc,
+    },
+};
+
+use crate::{
+    komm::{
+        self,
+        Echo,
+    },
+    Freie,
+    SklaveJob,
+};
+
+struct Order {
+    value: usize,
+    zusage: komm::Zusage<usize>,
+}
+
+struct Welt;
+
+struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+    fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+        Self(job_unit)
+    }
+}
+
+impl<J> edeltraud::Job for JobUnit<J> {
+    fn run(self) {
+        self.0.job
+            .ausfuehren(|_welt, Order { value, zusage, }| {
+                if value > 0 {
+                    zusage.commit_echo(value * 2).unwrap();
+                }
+            })
+            .unwrap();
+    }
+}
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F>(future: F) -> F::Output where F: Future {
+    let waker = task::Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = task::Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) =>
+                return output,
+            Poll::Pending =>
+                thread::park(),
+        }
+    }
+}
+
+#[test]
+fn await_reply_and_cancel() {
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(Welt, &thread_pool)
+        .unwrap();
+
+    let (zusage, erwartung) = komm::zusage();
+    meister.befehl(Order { value: 21, zusage, }, &thread_pool).unwrap();
+    assert_eq!(block_on(erwartung), Ok(42));
+
+    let (zusage, erwartung) = komm::zusage();
+    meister.befehl(Order { value: 0, zusage, }, &thread_pool).unwrap();
+    assert_eq!(block_on(erwartung), Err(komm::Abgebrochen));
+
+    let (zusage, erwartung) = komm::zusage::<usize>();
+    drop(erwartung);
+    assert!(zusage.commit_echo(1).is_err());
+}
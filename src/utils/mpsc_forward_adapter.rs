@@ -6,9 +6,11 @@ use std::{
 
 use crate::{
     ewig,
+    arbeit::{
+        Arbeitssklave,
+        Context,
+    },
     Meister,
-    Gehorsam,
-    SklavenBefehl,
 };
 
 #[derive(Debug)]
@@ -34,7 +36,7 @@ impl<B> Adapter<B> {
         thread_pool: &edeltraud::Handle<J>,
     )
         -> Result<Adapter<B>, Error>
-    where J: From<SklaveJob<B>>,
+    where J: From<SklaveJob<B>> + Send + 'static,
           B: Send + 'static,
     {
         let ewig_freie = ewig::Freie::new();
@@ -54,6 +56,15 @@ pub struct Welt<B> {
     ewig_meister: ewig::Meister<B, Error>,
 }
 
+impl<B, J> Arbeitssklave<B, J> for Welt<B> {
+    fn befehl(&mut self, befehl: B, context: &mut Context<'_, Self, B, J>) {
+        if let Err(send_error) = self.ewig_meister.befehl(befehl) {
+            log::debug!("befehl forward failed for {}: {send_error:?}", context.meister().kennung());
+            context.beenden();
+        }
+    }
+}
+
 fn forward<B>(sklave: &mut ewig::Sklave<B, Error>, sync_tx: &mpsc::SyncSender<B>) -> Result<(), Error> {
     loop {
         for befehl in sklave.zu_ihren_diensten()? {
@@ -87,31 +98,12 @@ impl<B, J> From<edeltraud::JobUnit<J, Job<B>>> for JobUnit<B, J> {
 impl<B, J> edeltraud::Job for JobUnit<B, J> {
     fn run(self) {
         match self.0.job {
-            Job::Sklave(mut sklave_job) =>
-                loop {
-                    match sklave_job.zu_ihren_diensten().unwrap() {
-                        Gehorsam::Rasten =>
-                            break,
-                        Gehorsam::Machen { mut befehle, } =>
-                            loop {
-                                match befehle.befehl() {
-                                    SklavenBefehl::Mehr { befehl, mehr_befehle, } => {
-                                        befehle = mehr_befehle;
-                                        if let Err(send_error) = befehle.ewig_meister.befehl(befehl) {
-                                            log::debug!("befehl forward failed: {send_error:?}");
-                                            return;
-                                        }
-                                    },
-                                    SklavenBefehl::Ende {
-                                        sklave_job: next_sklave_job,
-                                    } => {
-                                        sklave_job = next_sklave_job;
-                                        break;
-                                    },
-                                }
-                            },
-                    }
-                },
+            Job::Sklave(sklave_job) => {
+                let meister = sklave_job.meister();
+                if let Err(error) = sklave_job.arbeiten(&self.0.handle) {
+                    log::debug!("sklave job for {} terminated: {error:?}", meister.kennung());
+                }
+            },
         }
     }
 }
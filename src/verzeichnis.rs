@@ -0,0 +1,125 @@
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    sync::{
+        Arc,
+        Weak,
+        Mutex,
+    },
+    collections::{
+        HashMap,
+    },
+};
+
+use crate::{
+    komm,
+    ActorId,
+    Kennung,
+    Meister,
+    SklaveJob,
+};
+
+#[derive(Clone, Default)]
+pub struct Verzeichnis {
+    inner: Arc<Mutex<Eintraege>>,
+}
+
+type Eintraege = HashMap<(String, TypeId), Eintrag>;
+
+struct Eintrag {
+    actor_id: ActorId,
+    sendegeraet: Box<dyn Any + Send + Sync>,
+    is_alive: Box<dyn Fn() -> bool + Send + Sync>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    AlreadyRegistered(Kennung),
+    MutexPoisoned,
+}
+
+impl Verzeichnis {
+    pub fn new() -> Verzeichnis {
+        Verzeichnis::default()
+    }
+
+    pub fn registrieren<W, B, J, N>(
+        &self,
+        name: N,
+        meister: &Meister<W, B>,
+        thread_pool: edeltraud::Handle<J>,
+    )
+        -> Result<(), Error>
+    where J: From<SklaveJob<W, B>> + Send + 'static,
+          W: Send + 'static,
+          B: Send + 'static,
+          N: Into<String>,
+    {
+        let mut eintraege = self.inner.lock()
+            .map_err(|_| Error::MutexPoisoned)?;
+        eintraege.retain(|_, eintrag| (eintrag.is_alive)());
+
+        let key = (name.into(), TypeId::of::<B>());
+        if let Some(eintrag) = eintraege.get(&key) {
+            let kennung = Kennung { actor_id: eintrag.actor_id, name: Some(key.0), };
+            return Err(Error::AlreadyRegistered(kennung));
+        }
+
+        let schwach_meister = meister.downgrade();
+        let actor_id = meister.actor_id();
+        eintraege.insert(key.clone(), Eintrag {
+            actor_id,
+            sendegeraet: Box::new(komm::Sendegeraet::starten(meister, thread_pool)),
+            is_alive: Box::new(move || {
+                schwach_meister.upgrade()
+                    .map(|meister| meister.status())
+                    .is_some_and(|status| status.terminated.is_none() && !status.is_shutdown)
+            }),
+        });
+        drop(eintraege);
+
+        // the hook may run right away on this thread, so it is installed without holding the lock
+        let schwach_inner = Arc::downgrade(&self.inner);
+        meister.inner.beendet_hook_installieren(Box::new(move || austragen(&schwach_inner, &key, actor_id)));
+        Ok(())
+    }
+
+    pub fn finden<B>(&self, name: &str) -> Option<komm::Sendegeraet<B>> where B: 'static {
+        let mut eintraege = self.inner.lock().ok()?;
+        lebendig(&mut eintraege, (name.to_string(), TypeId::of::<B>()))
+    }
+
+    pub fn finden_nach_id<B>(&self, actor_id: ActorId) -> Option<komm::Sendegeraet<B>> where B: 'static {
+        let mut eintraege = self.inner.lock().ok()?;
+        let key = eintraege.iter()
+            .find(|((_, type_id), eintrag)| *type_id == TypeId::of::<B>() && eintrag.actor_id == actor_id)
+            .map(|(key, _)| key.clone())?;
+        lebendig(&mut eintraege, key)
+    }
+}
+
+fn austragen(schwach_inner: &Weak<Mutex<Eintraege>>, key: &(String, TypeId), actor_id: ActorId) {
+    let Some(inner) = schwach_inner.upgrade() else {
+        return;
+    };
+    let Ok(mut eintraege) = inner.lock() else {
+        return;
+    };
+    // the name might have been taken over by another actor in the meantime
+    if eintraege.get(key).is_some_and(|eintrag| eintrag.actor_id == actor_id) {
+        eintraege.remove(key);
+    }
+}
+
+fn lebendig<B>(eintraege: &mut Eintraege, key: (String, TypeId)) -> Option<komm::Sendegeraet<B>> where B: 'static {
+    let eintrag = eintraege.get(&key)?;
+    if !(eintrag.is_alive)() {
+        eintraege.remove(&key);
+        return None;
+    }
+    eintrag.sendegeraet
+        .downcast_ref::<komm::Sendegeraet<B>>()
+        .cloned()
+}
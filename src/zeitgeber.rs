@@ -0,0 +1,308 @@
+use std::{
+    mem,
+    panic,
+    collections::{
+        HashMap,
+    },
+    time::{
+        Instant,
+        Duration,
+    },
+    sync::{
+        atomic,
+        Arc,
+        Mutex,
+        OnceLock,
+    },
+};
+
+use crate::{
+    ewig,
+    komm,
+    Error,
+    SendError,
+    TerminationReason,
+};
+
+pub(crate) const TICK: Duration = Duration::from_millis(1);
+const SLOTS_COUNT: usize = 512;
+
+pub struct Wecker<B> {
+    maybe_order: Arc<Mutex<Option<B>>>,
+    abbrecher: Abbrecher,
+}
+
+impl<B> Wecker<B> {
+    pub fn abbrechen(&self) -> Option<B> {
+        let order = self.maybe_order.lock().ok()?.take()?;
+        self.abbrecher.abbrechen();
+        Some(order)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IntervallPolicy {
+    FixedRate,
+    FixedDelay,
+}
+
+#[derive(Debug)]
+pub enum IntervallError {
+    ZeroPeriod,
+    Planen(Error),
+}
+
+pub struct Intervall {
+    abgebrochen: Arc<atomic::AtomicBool>,
+}
+
+impl Intervall {
+    pub fn abbrechen(&self) {
+        self.abgebrochen.store(true, atomic::Ordering::SeqCst);
+    }
+}
+
+struct IntervallZustand<B, F> {
+    sendegeraet: komm::Sendegeraet<B>,
+    make_order: F,
+    period: Duration,
+    policy: IntervallPolicy,
+    frist: Instant,
+    abgebrochen: Arc<atomic::AtomicBool>,
+}
+
+pub(crate) struct Planung {
+    pub(crate) frist: Instant,
+    pub(crate) aktion: Box<dyn FnOnce() + Send + 'static>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Abbrecher {
+    planung_id: u64,
+}
+
+impl Abbrecher {
+    pub(crate) fn abbrechen(&self) {
+        let befehl = ZeitgeberBefehl::Abbrechen { planung_id: self.planung_id, };
+        if let Err(error) = zeitgeber().and_then(|zeitgeber| zeitgeber.befehl(befehl).map_err(zeitgeber_error)) {
+            log::debug!("failed to cancel planung {}: {error:?}", self.planung_id);
+        }
+    }
+}
+
+enum ZeitgeberBefehl {
+    Planen {
+        planung_id: u64,
+        planung: Planung,
+    },
+    Abbrechen {
+        planung_id: u64,
+    },
+}
+
+fn zeitgeber() -> Result<&'static ewig::Meister<ZeitgeberBefehl, ewig::Error>, Error> {
+    static ZEITGEBER: OnceLock<ewig::Meister<ZeitgeberBefehl, ewig::Error>> = OnceLock::new();
+    if let Some(meister) = ZEITGEBER.get() {
+        return Ok(meister);
+    }
+    let meister = ewig::Freie::new()
+        .versklaven_als("arbeitssklave::zeitgeber".to_string(), zeitgeber_job)
+        .map_err(|error| {
+            log::error!("failed to spawn zeitgeber thread: {error:?}");
+            zeitgeber_error(error)
+        })?;
+    // on a concurrent start the losing meister is dropped here together with its thread
+    Ok(ZEITGEBER.get_or_init(|| meister))
+}
+
+fn zeitgeber_error(error: ewig::Error) -> Error {
+    match error {
+        ewig::Error::Terminated(reason) =>
+            Error::Terminated(reason),
+        ewig::Error::ThreadSpawn(..) =>
+            Error::Terminated(TerminationReason::ThreadPoolSpawn),
+    }
+}
+
+pub(crate) fn planen(planung: Planung) -> Result<Abbrecher, Error> {
+    static PLANUNG_COUNTER: atomic::AtomicU64 = atomic::AtomicU64::new(0);
+    let planung_id = PLANUNG_COUNTER.fetch_add(1, atomic::Ordering::Relaxed);
+    zeitgeber()?
+        .befehl(ZeitgeberBefehl::Planen { planung_id, planung, })
+        .map(|()| Abbrecher { planung_id, })
+        .map_err(zeitgeber_error)
+}
+
+fn zeitgeber_job(sklave: &mut ewig::Sklave<ZeitgeberBefehl, ewig::Error>) -> Result<(), ewig::Error> {
+    let mut rad = Rad::new(Instant::now());
+    loop {
+        for befehl in sklave.zu_ihren_diensten_bis(rad.naechste_frist())? {
+            match befehl {
+                ZeitgeberBefehl::Planen { planung_id, planung, } =>
+                    rad.einfuegen(planung_id, planung),
+                ZeitgeberBefehl::Abbrechen { planung_id, } =>
+                    rad.entfernen(planung_id),
+            }
+        }
+        rad.drehen(Instant::now());
+    }
+}
+
+struct Rad {
+    start: Instant,
+    cursor: u64,
+    slots: Vec<Vec<(u64, u64, Planung)>>,
+    planungen: HashMap<u64, u64>,
+}
+
+impl Rad {
+    fn new(start: Instant) -> Rad {
+        Rad {
+            start,
+            cursor: 0,
+            slots: (0 .. SLOTS_COUNT).map(|_| Vec::new()).collect(),
+            planungen: HashMap::new(),
+        }
+    }
+
+    fn tick_aufrunden(&self, frist: Instant) -> u64 {
+        let nanos = frist.saturating_duration_since(self.start).as_nanos();
+        nanos.div_ceil(TICK.as_nanos()) as u64
+    }
+
+    fn einfuegen(&mut self, planung_id: u64, planung: Planung) {
+        let tick = self.tick_aufrunden(planung.frist).max(self.cursor);
+        self.slots[tick as usize % SLOTS_COUNT].push((tick, planung_id, planung));
+        self.planungen.insert(planung_id, tick);
+    }
+
+    fn entfernen(&mut self, planung_id: u64) {
+        // planung could have already been fired
+        if let Some(tick) = self.planungen.remove(&planung_id) {
+            self.slots[tick as usize % SLOTS_COUNT]
+                .retain(|(_, slot_planung_id, _)| *slot_planung_id != planung_id);
+        }
+    }
+
+    fn drehen(&mut self, now: Instant) {
+        let now_tick = (now.saturating_duration_since(self.start).as_nanos() / TICK.as_nanos()) as u64;
+        while self.cursor <= now_tick {
+            if self.planungen.is_empty() {
+                self.cursor = now_tick + 1;
+                break;
+            }
+            let cursor = self.cursor;
+            let slot = &mut self.slots[cursor as usize % SLOTS_COUNT];
+            if !slot.is_empty() {
+                let (faellig, spaeter): (Vec<_>, Vec<_>) = mem::take(slot)
+                    .into_iter()
+                    .partition(|(tick, _, _)| *tick <= cursor);
+                *slot = spaeter;
+                for (_, planung_id, planung) in faellig {
+                    self.planungen.remove(&planung_id);
+                    (planung.aktion)();
+                }
+            }
+            self.cursor += 1;
+        }
+    }
+
+    fn naechste_frist(&self) -> Option<Instant> {
+        if self.planungen.is_empty() {
+            return None;
+        }
+        (self.cursor .. self.cursor + SLOTS_COUNT as u64)
+            .find(|tick| !self.slots[*tick as usize % SLOTS_COUNT].is_empty())
+            .map(|tick| self.start + Duration::from_nanos(tick.saturating_mul(TICK.as_nanos() as u64)))
+    }
+}
+
+impl<B> komm::Sendegeraet<B> where B: Send + 'static {
+    pub fn befehl_nach(&self, delay: Duration, order: B) -> Result<Wecker<B>, SendError<B>> {
+        self.befehl_um(Instant::now() + delay, order)
+    }
+
+    pub fn befehl_um(&self, frist: Instant, order: B) -> Result<Wecker<B>, SendError<B>> {
+        let maybe_order = Arc::new(Mutex::new(Some(order)));
+        let sendegeraet = self.clone();
+        let fire_maybe_order = maybe_order.clone();
+        let aktion = Box::new(move || {
+            sendegeraet.zeitgeber_zustellen(fire_maybe_order, |order| order);
+        });
+
+        match planen(Planung { frist, aktion, }) {
+            Ok(abbrecher) =>
+                Ok(Wecker { maybe_order, abbrecher, }),
+            Err(error) =>
+                Err(SendError {
+                    order: maybe_order.lock().ok().and_then(|mut maybe_order| maybe_order.take()).unwrap(),
+                    error,
+                    kennung: self.kennung(),
+                }),
+        }
+    }
+
+    pub fn intervall<F>(&self, period: Duration, policy: IntervallPolicy, make_order: F) -> Result<Intervall, IntervallError>
+    where F: FnMut() -> B + Send + 'static,
+    {
+        if period.is_zero() {
+            return Err(IntervallError::ZeroPeriod);
+        }
+        let abgebrochen = Arc::new(atomic::AtomicBool::new(false));
+        let zustand = IntervallZustand {
+            sendegeraet: self.clone(),
+            make_order,
+            period,
+            policy,
+            frist: Instant::now() + period,
+            abgebrochen: abgebrochen.clone(),
+        };
+        zustand.planen()
+            .map_err(IntervallError::Planen)?;
+        Ok(Intervall { abgebrochen, })
+    }
+}
+
+impl<B, F> IntervallZustand<B, F> where B: Send + 'static, F: FnMut() -> B + Send + 'static {
+    fn planen(self) -> Result<(), Error> {
+        let frist = self.frist;
+        planen(Planung { frist, aktion: Box::new(move || self.feuern()), })
+            .map(|_abbrecher| ())
+    }
+
+    fn feuern(mut self) {
+        if self.abgebrochen.load(atomic::Ordering::SeqCst) {
+            return;
+        }
+        let order = match panic::catch_unwind(panic::AssertUnwindSafe(&mut self.make_order)) {
+            Ok(order) =>
+                order,
+            Err(..) => {
+                // a panicking closure stops only its own subscription, zeitgeber keeps running
+                log::error!("intervall for {} stopped: make_order panicked", self.sendegeraet.kennung());
+                return;
+            },
+        };
+        match self.sendegeraet.try_befehl(order) {
+            Ok(()) =>
+                (),
+            Err(SendError { error: Error::Full, kennung, .. }) =>
+                // mailbox is full: skip this tick
+                log::debug!("intervall for {kennung} skipped a tick: mailbox is full"),
+            Err(send_error) => {
+                // target is gone or terminated: subscription stops here
+                log::debug!("intervall for {} stopped: {:?}", send_error.kennung, send_error.error);
+                return;
+            },
+        }
+        self.frist = match self.policy {
+            IntervallPolicy::FixedRate =>
+                self.frist + self.period,
+            IntervallPolicy::FixedDelay =>
+                Instant::now() + self.period,
+        };
+        if let Err(error) = self.planen() {
+            log::error!("intervall rescheduling failed: {error:?}");
+        }
+    }
+}
@@ -0,0 +1,208 @@
+#![cfg(feature = "tracing")]
+
+use std::{
+    fmt,
+    cell::{
+        RefCell,
+    },
+    sync::{
+        atomic,
+        mpsc,
+        Arc,
+        Mutex,
+        OnceLock,
+    },
+};
+
+use tracing::{
+    field,
+    span,
+    Event,
+    Metadata,
+    Subscriber,
+};
+
+use arbeitssklave::{
+    Freie,
+    GehorsamEinmal,
+    SklaveJob,
+};
+
+thread_local! {
+    static ENTERED: RefCell<Vec<(span::Id, &'static Metadata<'static>)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Default)]
+struct Recorded {
+    spans: Mutex<Vec<(u64, &'static Metadata<'static>, Option<String>)>>,
+    follows_from: Mutex<Vec<(u64, u64)>>,
+}
+
+struct Recorder {
+    next_id: atomic::AtomicU64,
+    recorded: Arc<Recorded>,
+}
+
+struct ActorIdVisitor(Option<String>);
+
+impl field::Visit for ActorIdVisitor {
+    fn record_debug(&mut self, field: &field::Field, value: &dyn fmt::Debug) {
+        if field.name() == "actor_id" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+        let id = self.next_id.fetch_add(1, atomic::Ordering::Relaxed);
+        let mut visitor = ActorIdVisitor(None);
+        attrs.record(&mut visitor);
+        self.recorded.spans.lock().unwrap().push((id, attrs.metadata(), visitor.0));
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) { }
+
+    fn record_follows_from(&self, span: &span::Id, follows: &span::Id) {
+        self.recorded.follows_from.lock().unwrap().push((span.into_u64(), follows.into_u64()));
+    }
+
+    fn event(&self, _event: &Event<'_>) { }
+
+    fn enter(&self, span: &span::Id) {
+        let spans = self.recorded.spans.lock().unwrap();
+        let (_, metadata, _) = spans.iter().find(|(id, _, _)| *id == span.into_u64()).unwrap();
+        ENTERED.with(|entered| entered.borrow_mut().push((span.clone(), metadata)));
+    }
+
+    fn exit(&self, _span: &span::Id) {
+        ENTERED.with(|entered| entered.borrow_mut().pop());
+    }
+
+    fn current_span(&self) -> tracing_core::span::Current {
+        ENTERED.with(|entered| match entered.borrow().last() {
+            Some((id, metadata)) =>
+                tracing_core::span::Current::new(id.clone(), metadata),
+            None =>
+                tracing_core::span::Current::none(),
+        })
+    }
+}
+
+fn recorded() -> Arc<Recorded> {
+    static RECORDED: OnceLock<Arc<Recorded>> = OnceLock::new();
+    RECORDED
+        .get_or_init(|| {
+            let recorded = Arc::new(Recorded::default());
+            tracing::subscriber::set_global_default(Recorder {
+                next_id: atomic::AtomicU64::new(1),
+                recorded: recorded.clone(),
+            }).unwrap();
+            recorded
+        })
+        .clone()
+}
+
+#[test]
+fn befehl_follows_from_sender() {
+    struct Welt {
+        done_tx: mpsc::Sender<()>,
+    }
+
+    struct Order;
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            self.0.job
+                .ausfuehren(|welt, Order| welt.done_tx.send(()).unwrap())
+                .unwrap();
+        }
+    }
+
+    let recorded = recorded();
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let meister = Freie::new()
+        .versklaven(Welt { done_tx, }, &thread_pool)
+        .unwrap();
+    let actor_id = Some(format!("{:?}", meister.actor_id()));
+
+    let sender_span = tracing::info_span!("sender");
+    sender_span.in_scope(|| meister.befehl(Order, &thread_pool).unwrap());
+    done_rx.recv().unwrap();
+
+    let sender_id = sender_span.id().unwrap().into_u64();
+    let spans = recorded.spans.lock().unwrap().clone();
+    assert!(spans.iter().any(|(_, metadata, span_actor_id)| metadata.name() == "sklave_job" && *span_actor_id == actor_id));
+    let befehl_ids: Vec<_> = spans.iter()
+        .filter(|(_, metadata, span_actor_id)| metadata.name() == "befehl" && *span_actor_id == actor_id)
+        .map(|(id, _, _)| *id)
+        .collect();
+    assert_eq!(befehl_ids.len(), 1);
+    assert!(recorded.follows_from.lock().unwrap().contains(&(befehl_ids[0], sender_id)));
+}
+
+#[test]
+fn befehl_span_exited_after_einmal() {
+    struct Welt;
+
+    struct Order;
+
+    struct JobUnit<J>(edeltraud::JobUnit<J, SklaveJob<Welt, Order>>);
+
+    impl<J> From<edeltraud::JobUnit<J, SklaveJob<Welt, Order>>> for JobUnit<J> {
+        fn from(job_unit: edeltraud::JobUnit<J, SklaveJob<Welt, Order>>) -> Self {
+            Self(job_unit)
+        }
+    }
+
+    static CURRENT_TX: OnceLock<Mutex<mpsc::Sender<Option<&'static str>>>> = OnceLock::new();
+
+    impl<J> edeltraud::Job for JobUnit<J> {
+        fn run(self) {
+            let mut sklave_job = self.0.job;
+            while let GehorsamEinmal::Machen { befehl: Order, sklave_job: next_sklave_job, } =
+                sklave_job.zu_ihren_diensten_einmal().unwrap()
+            {
+                let current = tracing::Span::current().metadata().map(Metadata::name);
+                CURRENT_TX.get().unwrap().lock().unwrap().send(current).unwrap();
+                sklave_job = next_sklave_job;
+            }
+        }
+    }
+
+    recorded();
+    let (current_tx, current_rx) = mpsc::channel();
+    CURRENT_TX.set(Mutex::new(current_tx)).unwrap();
+
+    let edeltraud = edeltraud::Builder::new()
+        .build::<_, JobUnit<_>>()
+        .unwrap();
+    let thread_pool = edeltraud.handle();
+
+    let meister = Freie::new()
+        .versklaven(Welt, &thread_pool)
+        .unwrap();
+    meister.befehl(Order, &thread_pool).unwrap();
+    meister.befehl(Order, &thread_pool).unwrap();
+    assert_ne!(current_rx.recv().unwrap(), Some("befehl"));
+    assert_ne!(current_rx.recv().unwrap(), Some("befehl"));
+}